@@ -4,7 +4,7 @@ mod cigar;
 pub mod data;
 mod flags;
 mod mapping_quality;
-mod name;
+pub(crate) mod name;
 mod position;
 mod quality_scores;
 mod reference_sequence_id;
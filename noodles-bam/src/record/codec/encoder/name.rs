@@ -30,7 +30,7 @@ where
     Ok(())
 }
 
-fn is_valid(buf: &[u8]) -> bool {
+pub(crate) fn is_valid(buf: &[u8]) -> bool {
     (1..=MAX_LENGTH).contains(&buf.len())
         && buf != MISSING
         && buf.iter().all(|&b| b.is_ascii_graphic() && b != b'@')
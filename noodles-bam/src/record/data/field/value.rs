@@ -173,4 +173,33 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fmt() -> io::Result<()> {
+        use noodles_sam::alignment::record_buf::data::field::Value as ValueBuf;
+
+        fn t(mut src: &[u8], ty: Type, expected: &ValueBuf) -> io::Result<()> {
+            let actual = decode_value(&mut src, ty)?;
+            assert_eq!(actual.to_string(), expected.to_string());
+            Ok(())
+        }
+
+        t(b"n", Type::Character, &ValueBuf::Character(b'n'))?;
+        t(&[0xf8], Type::Int8, &ValueBuf::Int8(-8))?;
+        t(&[0x0d, 0x00], Type::UInt16, &ValueBuf::UInt16(13))?;
+        t(
+            &[0x00, 0x00, 0x00, 0x40],
+            Type::Float,
+            &ValueBuf::Float(2.0),
+        )?;
+        t(b"ndls\x00", Type::String, &ValueBuf::from("ndls"))?;
+        t(b"CAFE\x00", Type::Hex, &ValueBuf::Hex(b"CAFE".into()))?;
+        t(
+            &[b'c', 0x02, 0x00, 0x00, 0x00, 0x01, 0x02],
+            Type::Array,
+            &ValueBuf::from(vec![1i8, 2]),
+        )?;
+
+        Ok(())
+    }
 }
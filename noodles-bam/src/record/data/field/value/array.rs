@@ -1,5 +1,6 @@
 //! BAM record data field array value.
 
+mod buf;
 mod subtype;
 mod values;
 
@@ -9,7 +10,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_sam::alignment::record::data::field::value::{array::Subtype, Array};
 
 pub(crate) use self::subtype::decode_subtype;
-pub use self::values::Values;
+pub use self::{buf::Buf, values::Values};
 
 pub(super) fn decode_array<'a>(src: &mut &'a [u8]) -> io::Result<Array<'a>> {
     let subtype = decode_subtype(src)?;
@@ -115,4 +116,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_len_and_get_i64() -> io::Result<()> {
+        let mut src = &[
+            b'i', // subtype = Int32
+            0x03, 0x00, 0x00, 0x00, // n = 3
+            0x08, 0x00, 0x00, 0x00, // values[0] = 8
+            0xf3, 0xff, 0xff, 0xff, // values[1] = -13
+            0xff, 0xff, 0xff, 0x7f, // values[2] = i32::MAX
+        ][..];
+
+        let array = decode_array(&mut src)?;
+        assert_eq!(array.len(), 3);
+        assert!(!array.is_empty());
+        assert_eq!(array.get_i64(0).transpose()?, Some(8));
+        assert_eq!(array.get_i64(1).transpose()?, Some(-13));
+        assert_eq!(array.get_i64(2).transpose()?, Some(i64::from(i32::MAX)));
+        assert!(array.get_i64(3).is_none());
+
+        let mut src = &[b'f', 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00][..];
+        let array = decode_array(&mut src)?;
+        assert_eq!(array.len(), 1);
+        assert!(array.get_i64(0).is_none());
+
+        Ok(())
+    }
 }
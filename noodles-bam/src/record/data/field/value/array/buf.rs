@@ -0,0 +1,116 @@
+use std::io;
+
+use noodles_sam::alignment::record::data::field::value::Array;
+
+use super::decode_array;
+
+/// An owned BAM record data field array value buffer.
+///
+/// This holds an encoded array (subtype, length, and values), which can be viewed as an
+/// [`Array`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Buf(Vec<u8>);
+
+impl Buf {
+    /// Returns the buffer as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::record::data::field::value::array::Buf;
+    /// use noodles_sam::alignment::record::data::field::value::Array;
+    ///
+    /// let buf = Buf::try_from(&[8u8, 13][..])?;
+    ///
+    /// let Array::UInt8(values) = buf.as_array()? else {
+    ///     panic!();
+    /// };
+    ///
+    /// let actual: Vec<_> = values.iter().collect::<Result<_, _>>()?;
+    /// assert_eq!(actual, [8, 13]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn as_array(&self) -> io::Result<Array<'_>> {
+        let mut src = &self.0[..];
+        decode_array(&mut src)
+    }
+}
+
+impl AsRef<[u8]> for Buf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+macro_rules! impl_from_slice {
+    ($ty:ty, $subtype:expr) => {
+        impl TryFrom<&[$ty]> for Buf {
+            type Error = io::Error;
+
+            fn try_from(values: &[$ty]) -> Result<Self, Self::Error> {
+                let len = u32::try_from(values.len())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+                let mut buf = Vec::with_capacity(
+                    1 + std::mem::size_of::<u32>() + std::mem::size_of_val(values),
+                );
+
+                buf.push($subtype);
+                buf.extend(len.to_le_bytes());
+
+                for n in values {
+                    buf.extend(n.to_le_bytes());
+                }
+
+                Ok(Self(buf))
+            }
+        }
+    };
+}
+
+impl_from_slice!(i8, b'c');
+impl_from_slice!(u8, b'C');
+impl_from_slice!(i16, b's');
+impl_from_slice!(u16, b'S');
+impl_from_slice!(i32, b'i');
+impl_from_slice!(u32, b'I');
+impl_from_slice!(f32, b'f');
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_u8_slice() -> io::Result<()> {
+        let buf = Buf::try_from(&[0u8, 8, 13, 255][..])?;
+
+        assert_eq!(
+            buf.as_ref(),
+            [b'C', 0x04, 0x00, 0x00, 0x00, 0x00, 0x08, 0x0d, 0xff]
+        );
+
+        let mut src = buf.as_ref();
+        let Array::UInt8(values) = decode_array(&mut src)? else {
+            panic!();
+        };
+
+        let actual: Vec<_> = values.iter().collect::<Result<_, _>>()?;
+        assert_eq!(actual, [0, 8, 13, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_i32_slice() -> io::Result<()> {
+        let buf = Buf::try_from(&[-1i32, 8][..])?;
+
+        let Array::Int32(values) = buf.as_array()? else {
+            panic!();
+        };
+
+        let actual: Vec<_> = values.iter().collect::<Result<_, _>>()?;
+        assert_eq!(actual, [-1, 8]);
+
+        Ok(())
+    }
+}
@@ -33,6 +33,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, i8> for V
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<i8>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<i8>> {
+        get(self.src, i, i8::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, u8> {
@@ -50,6 +54,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, u8> for V
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<u8>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<u8>> {
+        get(self.src, i, u8::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, i16> {
@@ -72,6 +80,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, i16> for
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<i16>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<i16>> {
+        get(self.src, i, i16::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, u16> {
@@ -94,6 +106,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, u16> for
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<u16>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<u16>> {
+        get(self.src, i, u16::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, i32> {
@@ -116,6 +132,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, i32> for
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<i32>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<i32>> {
+        get(self.src, i, i32::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, u32> {
@@ -138,6 +158,10 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, u32> for
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<u32>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<u32>> {
+        get(self.src, i, u32::from_le_bytes)
+    }
 }
 
 impl<'a> Values<'a, f32> {
@@ -160,6 +184,26 @@ impl<'a> sam::alignment::record::data::field::value::array::Values<'a, f32> for
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<f32>> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<f32>> {
+        get(self.src, i, f32::from_le_bytes)
+    }
+}
+
+fn get<T, const N: usize>(
+    src: &[u8],
+    i: usize,
+    from_le_bytes: fn([u8; N]) -> T,
+) -> Option<io::Result<T>> {
+    let start = i.checked_mul(N)?;
+    let end = start.checked_add(N)?;
+
+    src.get(start..end).map(|chunk| {
+        let buf = chunk
+            .try_into()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(from_le_bytes(buf))
+    })
 }
 
 #[cfg(test)]
@@ -207,4 +251,22 @@ mod tests {
             2
         );
     }
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let values = Values::<'_, i8>::new(&[0xff, 0x08]);
+        assert_eq!(values.get(1).transpose()?, Some(8));
+        assert!(values.get(2).is_none());
+
+        let values = Values::<'_, u16>::new(&[0x08, 0x00, 0x0d, 0x00, 0x15]);
+        assert_eq!(values.get(0).transpose()?, Some(8));
+        assert_eq!(values.get(1).transpose()?, Some(13));
+        assert!(values.get(2).is_none());
+        assert!(values.get(usize::MAX).is_none());
+
+        let values = Values::<'_, f32>::new(&[0x00, 0x00, 0x00, 0x3f]);
+        assert_eq!(values.get(0).transpose()?, Some(0.5));
+
+        Ok(())
+    }
 }
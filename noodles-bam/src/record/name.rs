@@ -1,10 +1,15 @@
-use std::fmt;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    str,
+};
 
 use bstr::ByteSlice;
 use noodles_sam as sam;
 
 /// A BAM record name.
-#[derive(Eq, PartialEq)]
+///
+/// Names are compared and hashed without the trailing `NUL` terminator.
 pub struct Name<'a>(&'a [u8]);
 
 impl<'a> Name<'a> {
@@ -19,6 +24,36 @@ impl<'a> Name<'a> {
         const NUL: u8 = 0x00;
         self.as_ref().strip_suffix(&[NUL]).unwrap_or(self.as_ref())
     }
+
+    /// Returns the length of the name.
+    ///
+    /// This does _not_ include the trailing `NUL` terminator.
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    /// Returns whether the name is empty.
+    ///
+    /// This does _not_ consider the trailing `NUL` terminator.
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+
+    /// Converts the name to a string slice.
+    ///
+    /// The returned string will _not_ have the trailing `NUL` terminator.
+    pub fn to_str(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(self.as_bytes())
+    }
+
+    /// Returns whether the name is a valid SAM read name.
+    ///
+    /// A valid name is 1 to 254 characters long and only contains printable ASCII characters
+    /// excluding `@`. The missing name (`*`) is not a valid name.
+    pub fn is_valid(&self) -> bool {
+        use crate::record::codec::encoder::name::is_valid;
+        is_valid(self.as_bytes())
+    }
 }
 
 impl<'a> sam::alignment::record::Name for Name<'a> {
@@ -41,6 +76,38 @@ impl<'a> fmt::Debug for Name<'a> {
     }
 }
 
+impl<'a> fmt::Display for Name<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_bytes().as_bstr())
+    }
+}
+
+impl<'a> PartialEq for Name<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<'a> Eq for Name<'a> {}
+
+impl<'a> PartialEq<[u8]> for Name<'a> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl<'a, 'b> PartialEq<&'b [u8]> for Name<'a> {
+    fn eq(&self, other: &&'b [u8]) -> bool {
+        self.as_bytes() == *other
+    }
+}
+
+impl<'a> Hash for Name<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
 impl<'a> From<Name<'a>> for sam::alignment::record_buf::Name {
     fn from(name: Name<'a>) -> Self {
         Self::from(name.as_bytes())
@@ -60,6 +127,76 @@ mod tests {
         assert_eq!(name.as_bytes(), b"r0");
     }
 
+    #[test]
+    fn test_len() {
+        assert_eq!(Name::new(b"r0\x00").len(), 2);
+        assert_eq!(Name::new(b"r0").len(), 2);
+        assert!(Name::new(b"\x00").is_empty());
+    }
+
+    #[test]
+    fn test_eq_bytes() {
+        let name = Name::new(b"r0\x00");
+
+        let expected: &[u8] = b"r0";
+        assert!(name == *expected);
+        assert!(name == expected);
+
+        let other: &[u8] = b"r1";
+        assert!(name != *other);
+        assert!(name != other);
+
+        let other: &[u8] = b"r0\x00";
+        assert!(name != other);
+    }
+
+    #[test]
+    fn test_to_str() {
+        let name = Name::new(b"r0\x00");
+        assert_eq!(name.to_str(), Ok("r0"));
+
+        let name = Name::new(b"r\xff\x00");
+        assert!(name.to_str().is_err());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Name::new(b"r0\x00").is_valid());
+        assert!(Name::new(b"r0").is_valid());
+
+        assert!(!Name::new(b"\x00").is_valid());
+        assert!(!Name::new(b"r\xff\x00").is_valid());
+        assert!(!Name::new(b"r@0\x00").is_valid());
+        assert!(!Name::new(b"r 0\x00").is_valid());
+    }
+
+    #[test]
+    fn test_fmt() {
+        let name = Name::new(b"r0\x00");
+        assert_eq!(name.to_string(), "r0");
+
+        let name = Name::new(b"r\xff\x00");
+        assert_eq!(name.to_string(), "r\u{fffd}");
+    }
+
+    #[test]
+    fn test_eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(name: &Name<'_>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            name.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Name::new(b"r0\x00");
+        let b = Name::new(b"r0");
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+
+        assert_ne!(a, Name::new(b"r1\x00"));
+    }
+
     #[test]
     fn test_from_name_for_sam_alignment_record_buf_name() {
         use noodles_sam::alignment::record_buf::Name as NameBuf;
@@ -46,6 +46,18 @@ impl<'a> sam::alignment::record::Sequence for Sequence<'a> {
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
         Box::new(self.iter())
     }
+
+    fn get(&self, i: usize) -> Option<u8> {
+        use self::iter::decoded_bases;
+
+        if i < self.len() {
+            self.src
+                .get(i / 2)
+                .and_then(|&n| decoded_bases(n).nth(i % 2))
+        } else {
+            None
+        }
+    }
 }
 
 impl<'a> AsRef<[u8]> for Sequence<'a> {
@@ -59,3 +71,21 @@ impl<'a> From<Sequence<'a>> for sam::alignment::record_buf::Sequence {
         Self::from(sequence.as_ref().to_vec())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sam_alignment_record_sequence_get() {
+        use sam::alignment::record::Sequence as _;
+
+        // ACGTA
+        let sequence = Sequence::new(&[0x12, 0x48, 0x10], 5);
+
+        assert_eq!(sequence.get(0), Some(b'A'));
+        assert_eq!(sequence.get(3), Some(b'T'));
+        assert_eq!(sequence.get(4), Some(b'A'));
+        assert!(sequence.get(5).is_none());
+    }
+}
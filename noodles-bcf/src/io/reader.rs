@@ -417,6 +417,25 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        let mut contig_string_map = ContigStringMap::default();
+        contig_string_map.insert(String::from("sq0"));
+        contig_string_map.insert(String::from("sq1"));
+
+        let region = "sq1:8-13".parse()?;
+        let expected = contig_string_map.get_index_of("sq1");
+        assert_eq!(resolve_region(&contig_string_map, &region).ok(), expected);
+
+        let region = "sq2:8-13".parse()?;
+        assert!(matches!(
+            resolve_region(&contig_string_map, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_format_version() -> io::Result<()> {
         let data = [0x02, 0x01];
@@ -42,6 +42,15 @@ where
         }
     }
 
+    /// Returns the current virtual position of the underlying reader.
+    ///
+    /// After a record is yielded, this is the position directly after it. A reader seeked to this
+    /// position reads the next record in the stream, which does not necessarily intersect the
+    /// query region.
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.reader.virtual_position()
+    }
+
     fn next_record(&mut self) -> io::Result<Option<Record>> {
         read_record(&mut self.reader, &mut self.record).map(|n| match n {
             0 => None,
@@ -79,18 +88,9 @@ fn intersects(
     chromosome_id: usize,
     region_interval: Interval,
 ) -> io::Result<bool> {
-    let chromosome = record.reference_sequence_name(header.string_maps())?;
-
-    let id = header
-        .string_maps()
-        .contigs()
-        .get_index_of(chromosome)
-        .ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!("chromosome does not exist in contigs: {chromosome}"),
-            )
-        })?;
+    if record.reference_sequence_id()? != chromosome_id {
+        return Ok(false);
+    }
 
     let Some(start) = record.variant_start().transpose()? else {
         return Ok(false);
@@ -99,5 +99,82 @@ fn intersects(
     let end = record.variant_end(header)?;
     let record_interval = Interval::from(start..=end);
 
-    Ok(id == chromosome_id && record_interval.intersects(region_interval))
+    Ok(record_interval.intersects(region_interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::io::Write,
+        };
+
+        use crate::io::{Reader, Writer};
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for start in [1, 8, 13] {
+            let record = vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(start)?)
+                .set_reference_bases("A")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+        let data = writer.into_inner().into_inner();
+
+        let mut reader = Reader::new(Cursor::new(data.clone()));
+        let header = reader.read_header()?;
+        let start = reader.virtual_position();
+        let chunks = vec![Chunk::new(start, bgzf::VirtualPosition::MAX)];
+
+        let interval = Interval::from(Position::MIN..=Position::try_from(21)?);
+        let mut query = Query::new(reader.get_mut(), &header, chunks, 0, interval);
+
+        query.next().transpose()?;
+        let record = query.next().transpose()?.expect("missing record");
+        assert_eq!(record.variant_start().transpose()?, Position::new(8));
+        let virtual_position = query.virtual_position();
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_header()?;
+        reader.seek(virtual_position)?;
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(record.variant_start().transpose()?, Position::new(13));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::default();
+        let record = Record::default();
+
+        let interval = Interval::from(Position::MIN..=Position::MIN);
+        assert!(intersects(&header, &record, 0, interval)?);
+        assert!(!intersects(&header, &record, 1, interval)?);
+
+        let interval = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+        assert!(!intersects(&header, &record, 0, interval)?);
+
+        Ok(())
+    }
 }
@@ -13,6 +13,7 @@ use noodles_vcf::{self as vcf, header::StringMaps};
 pub use self::builder::Builder;
 use self::header::write_header;
 pub(crate) use self::record::write_record;
+pub use crate::record::codec::encoder::site::filters::MissingFilterError;
 use crate::Record;
 
 pub(crate) const MAJOR: u8 = 2;
@@ -17,8 +17,13 @@ use noodles_vcf as vcf;
 
 use self::info::read_info;
 pub(crate) use self::{
-    bases::read_ref_alt, chromosome_id::read_chrom, filters::read_filter, ids::read_id,
-    position::read_pos, quality_score::read_qual, string_map::read_string_map_entry,
+    bases::read_ref_alt,
+    chromosome_id::read_chrom,
+    filters::{filter_names, read_filter},
+    ids::read_id,
+    position::read_pos,
+    quality_score::read_qual,
+    string_map::read_string_map_entry,
 };
 pub use self::{samples::read_samples, value::read_value};
 
@@ -59,21 +64,8 @@ pub fn read_site(
 
     let filter_ids = read_filter(src)?;
 
-    *record.filters_mut() = filter_ids
-        .into_iter()
-        .map(|i| {
-            header
-                .string_maps()
-                .strings()
-                .get_index(i)
-                .map(String::from)
-                .ok_or_else(|| {
-                    io::Error::new(
-                        io::ErrorKind::InvalidInput,
-                        format!("invalid string map index: {i}"),
-                    )
-                })
-        })
+    *record.filters_mut() = filter_names(header.string_maps(), &filter_ids)
+        .map(|result| result.map(String::from))
         .collect::<io::Result<_>>()?;
 
     read_info(src, header, n_info, record.info_mut())
@@ -1,7 +1,62 @@
 use std::io;
 
+use noodles_vcf::header::StringMaps;
+
 use super::string_map::read_string_map_indices;
 
 pub(crate) fn read_filter(reader: &mut &[u8]) -> io::Result<Vec<usize>> {
     read_string_map_indices(reader).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
+
+/// Resolves filter string map indices to filter names.
+///
+/// The names are borrowed from the header string map, i.e., no intermediate list of owned
+/// names is built.
+pub(crate) fn filter_names<'a>(
+    string_maps: &'a StringMaps,
+    indices: &'a [usize],
+) -> impl Iterator<Item = io::Result<&'a str>> + 'a {
+    let strings = string_maps.strings();
+
+    indices.iter().map(move |&i| {
+        strings.get_index(i).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid filter string map index: {i}"),
+            )
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf as vcf;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_names() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::record::value::{map::Filter, Map};
+
+        let header = vcf::Header::builder()
+            .add_filter("PASS", Map::<Filter>::pass())
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let names: Vec<_> = filter_names(&string_maps, &[0]).collect::<io::Result<_>>()?;
+        assert_eq!(names, ["PASS"]);
+
+        let names: Vec<_> = filter_names(&string_maps, &[1]).collect::<io::Result<_>>()?;
+        assert_eq!(names, ["q10"]);
+
+        let mut iter = filter_names(&string_maps, &[2]);
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
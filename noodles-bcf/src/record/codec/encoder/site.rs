@@ -1,5 +1,5 @@
 mod bases;
-mod filters;
+pub(crate) mod filters;
 mod ids;
 mod info;
 mod position;
@@ -1,7 +1,40 @@
-use std::io::{self, Write};
+use std::{
+    error, fmt,
+    io::{self, Write},
+};
 
 use noodles_vcf::{self as vcf, header::StringMaps, variant::record::Filters};
 
+/// An error returned when a filter is missing from the header string map.
+///
+/// This is wrapped in an [`io::Error`] of kind [`io::ErrorKind::InvalidInput`] and can be
+/// retrieved using [`io::Error::get_ref`] and downcasting.
+///
+/// Filters must be declared in the VCF header as FILTER records (`##FILTER=<ID=...>`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingFilterError {
+    id: String,
+}
+
+impl MissingFilterError {
+    /// Returns the ID of the missing filter.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl error::Error for MissingFilterError {}
+
+impl fmt::Display for MissingFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "filter missing from string map: {}; add a FILTER record to the VCF header (##FILTER=<ID={},...>)",
+            self.id, self.id
+        )
+    }
+}
+
 pub(super) fn write_filters<W, F>(
     writer: &mut W,
     header: &vcf::Header,
@@ -22,7 +55,7 @@ where
             string_maps.strings().get_index_of(id).ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::InvalidInput,
-                    format!("filter missing from string map: {id}"),
+                    MissingFilterError { id: id.into() },
                 )
             })
         })
@@ -92,4 +125,50 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_filters_with_empty_filters() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::record_buf::Filters;
+
+        let header = vcf::Header::default();
+        let string_maps = StringMaps::try_from(&header)?;
+
+        // A missing FILTER (`.`) is encoded as an empty typed vector.
+        let mut buf = Vec::new();
+        write_filters(&mut buf, &header, &string_maps, Filters::default())?;
+        assert_eq!(buf, [0x00]);
+
+        // PASS is always the first entry in the string map.
+        buf.clear();
+        write_filters(&mut buf, &header, &string_maps, Filters::pass())?;
+        assert_eq!(buf, [0x11, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_filters_with_missing_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::record_buf::Filters;
+
+        let header = vcf::Header::default();
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let filters: Filters = [String::from("q10")].into_iter().collect();
+
+        let mut buf = Vec::new();
+        let e = write_filters(&mut buf, &header, &string_maps, &filters).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+
+        let source = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MissingFilterError>());
+        assert_eq!(source.map(|e| e.id()), Some("q10"));
+
+        assert_eq!(
+            e.to_string(),
+            "filter missing from string map: q10; add a FILTER record to the VCF header (##FILTER=<ID=q10,...>)"
+        );
+
+        Ok(())
+    }
 }
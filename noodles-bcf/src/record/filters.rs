@@ -82,3 +82,19 @@ impl<'r> vcf::variant::record::Filters for Filters<'r> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vcf::variant::record::Filters as _;
+
+    use super::*;
+
+    #[test]
+    fn test_is_empty() {
+        let filters = Filters::new(&[0x00]);
+        assert!(filters.is_empty());
+
+        let filters = Filters::new(&[0x11, 0x00]);
+        assert!(!filters.is_empty());
+    }
+}
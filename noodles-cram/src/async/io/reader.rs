@@ -143,6 +143,38 @@ where
         read_header_container(&mut self.inner, &mut self.buf).await
     }
 
+    /// Reads the raw SAM header into the given writer.
+    ///
+    /// This is the same as [`Self::read_file_header`] but writes the raw SAM header bytes to
+    /// `writer` instead of returning an owned [`String`], e.g., to pass them directly to a SAM
+    /// header parser. The bytes are not validated as UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_cram as cram;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.cram").await.map(cram::r#async::io::Reader::new)?;
+    /// reader.read_file_definition().await?;
+    ///
+    /// let mut raw_header = Vec::new();
+    /// reader.read_file_header_into(&mut raw_header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_file_header_into<W>(&mut self, writer: &mut W) -> io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        use self::header_container::read_header_container_into;
+        read_header_container_into(&mut self.inner, &mut self.buf, writer).await
+    }
+
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
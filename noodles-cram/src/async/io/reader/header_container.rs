@@ -1,19 +1,30 @@
 mod header;
 
-use std::str;
+use std::{io::Write, mem};
 
 use bytes::{Buf, Bytes, BytesMut};
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use self::header::read_header;
-use crate::container::{
-    block::{CompressionMethod, ContentType},
-    Block,
-};
+use crate::container::{block::ContentType, Block};
 
 pub async fn read_header_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<String>
 where
     R: AsyncRead + Unpin,
+{
+    let mut raw_header = Vec::new();
+    read_header_container_into(reader, buf, &mut raw_header).await?;
+    into_string(raw_header)
+}
+
+pub async fn read_header_container_into<R, W>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    writer: &mut W,
+) -> io::Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: Write,
 {
     let len = read_header(reader).await?;
 
@@ -21,33 +32,31 @@ where
     reader.read_exact(buf).await?;
     let mut buf = buf.split().freeze();
 
-    read_raw_sam_header_from_block(&mut buf)
+    read_raw_sam_header_from_block_into(&mut buf, writer)
 }
 
-fn read_raw_sam_header_from_block(src: &mut Bytes) -> io::Result<String> {
+fn read_raw_sam_header_from_block_into<W>(src: &mut Bytes, writer: &mut W) -> io::Result<()>
+where
+    W: Write,
+{
     use crate::io::reader::container::read_block;
 
     let block = read_block(src)?;
-    read_raw_sam_header(&block)
+    read_raw_sam_header_into(&block, writer)
 }
 
+#[cfg(test)]
 fn read_raw_sam_header(block: &Block) -> io::Result<String> {
-    const EXPECTED_CONTENT_TYPE: ContentType = ContentType::FileHeader;
+    let mut raw_header = Vec::new();
+    read_raw_sam_header_into(block, &mut raw_header)?;
+    into_string(raw_header)
+}
 
-    if !matches!(
-        block.compression_method(),
-        CompressionMethod::None | CompressionMethod::Gzip
-    ) {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "invalid block compression method: expected {:?} or {:?}, got {:?}",
-                CompressionMethod::None,
-                CompressionMethod::Gzip,
-                block.compression_method()
-            ),
-        ));
-    }
+fn read_raw_sam_header_into<W>(block: &Block, writer: &mut W) -> io::Result<()>
+where
+    W: Write,
+{
+    const EXPECTED_CONTENT_TYPE: ContentType = ContentType::FileHeader;
 
     if block.content_type() != EXPECTED_CONTENT_TYPE {
         return Err(io::Error::new(
@@ -62,14 +71,34 @@ fn read_raw_sam_header(block: &Block) -> io::Result<String> {
 
     let mut data = block.decompressed_data()?;
 
+    if data.len() < mem::size_of::<i32>() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing header length",
+        ));
+    }
+
     let len = usize::try_from(data.get_i32_le())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+    if len > data.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid header length: expected <= {}, got {}",
+                data.len(),
+                len
+            ),
+        ));
+    }
+
     data.truncate(len);
 
-    str::from_utf8(&data[..])
-        .map(|s| s.into())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    writer.write_all(&data[..])
+}
+
+fn into_string(buf: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 #[cfg(test)]
@@ -104,10 +133,101 @@ mod tests {
     }
 
     #[test]
-    fn test_read_raw_sam_header_with_invalid_compression_method() {
+    fn test_read_raw_sam_header_with_compression_methods() -> io::Result<()> {
+        use crate::{
+            codecs::{bzip2, gzip, lzma},
+            container::block::CompressionMethod,
+        };
+
+        let raw_header = "@HD\tVN:1.6\n@CO\tnoodles-cram\n";
+
+        let header_data_len = i32::try_from(raw_header.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut data = Vec::new();
+        data.put_i32_le(header_data_len);
+        data.extend(raw_header.as_bytes());
+
+        for (compression_method, compressed_data) in [
+            (CompressionMethod::None, data.clone()),
+            (
+                CompressionMethod::Gzip,
+                gzip::encode(flate2::Compression::default(), &data)?,
+            ),
+            (
+                CompressionMethod::Bzip2,
+                bzip2::encode(::bzip2::Compression::default(), &data)?,
+            ),
+            (CompressionMethod::Lzma, lzma::encode(6, &data)?),
+        ] {
+            let block = Block::builder()
+                .set_compression_method(compression_method)
+                .set_content_type(ContentType::FileHeader)
+                .set_uncompressed_len(data.len())
+                .set_data(compressed_data.into())
+                .build();
+
+            let actual = read_raw_sam_header(&block)?;
+            assert_eq!(actual, raw_header);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_raw_sam_header_into() -> io::Result<()> {
+        let raw_header = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n@SQ\tSN:sq1\tLN:13\n@CO\tnoodles-cram\n";
+
+        let header_data_len = i32::try_from(raw_header.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut data = Vec::new();
+        data.put_i32_le(header_data_len);
+        data.extend(raw_header.as_bytes());
+
         let block = Block::builder()
-            .set_compression_method(CompressionMethod::Lzma)
             .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.into())
+            .build();
+
+        let mut actual = Vec::new();
+        read_raw_sam_header_into(&block, &mut actual)?;
+
+        assert_eq!(actual, read_raw_sam_header(&block)?.into_bytes());
+        assert_eq!(actual, raw_header.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_raw_sam_header_with_invalid_header_length() {
+        let raw_header = "@HD\tVN:1.6\n";
+
+        let mut data = Vec::new();
+        data.put_i32_le(64);
+        data.extend(raw_header.as_bytes());
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.into())
+            .build();
+
+        assert!(matches!(
+            read_raw_sam_header(&block),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_raw_sam_header_with_missing_header_length() {
+        let data = vec![0x00, 0x00];
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.into())
             .build();
 
         assert!(matches!(
@@ -78,7 +78,7 @@ where
     /// ```
     pub async fn shutdown(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
-        self.flush(header).await?;
+        self.flush_container(header).await?;
         write_eof_container(&mut self.inner).await
     }
 
@@ -109,7 +109,9 @@ where
     /// # }
     /// ```
     pub async fn write_file_definition(&mut self) -> io::Result<()> {
-        let file_definition = FileDefinition::default();
+        self.options.validate_version()?;
+
+        let file_definition = FileDefinition::new(self.options.version, Default::default());
         write_file_definition(&mut self.inner, &file_definition).await
     }
 
@@ -186,7 +188,10 @@ where
         use crate::data_container::builder::AddRecordError;
 
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -194,7 +199,7 @@ where
                 Err(e) => match e {
                     AddRecordError::ContainerFull(r) => {
                         record = r;
-                        self.flush(header).await?;
+                        self.flush_container(header).await?;
                     }
                     AddRecordError::SliceFull(r) => {
                         record = r;
@@ -205,7 +210,35 @@ where
         }
     }
 
-    async fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
+    /// Writes any pending records as a data container, even if it is not full.
+    ///
+    /// This is useful for streaming output, where downstream readers can consume records before
+    /// the writer is shut down. If there are no pending records, this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    /// use tokio::io;
+    ///
+    /// let mut writer = cram::r#async::io::Writer::new(io::sink());
+    /// writer.write_file_definition().await?;
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_file_header(&header).await?;
+    ///
+    /// let record = cram::Record::default();
+    /// writer.write_record(&header, record).await?;
+    /// writer.flush_container(&header).await?;
+    ///
+    /// writer.shutdown(&header).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn flush_container(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::data_container::write_data_container;
 
         if self.data_container_builder.is_empty() {
@@ -249,3 +282,110 @@ where
     let format = [version.major(), version.minor()];
     writer.write_all(&format).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_file_definition_with_version() -> io::Result<()> {
+        use super::Builder;
+
+        let mut writer = Builder::default()
+            .set_version(Version::new(3, 1))
+            .build_with_writer(Vec::new());
+        writer.write_file_definition().await?;
+        assert_eq!(&writer.get_ref()[..6], [b'C', b'R', b'A', b'M', 0x03, 0x01]);
+
+        let mut writer = Builder::default()
+            .set_version(Version::new(4, 0))
+            .build_with_writer(Vec::new());
+        assert!(matches!(
+            writer.write_file_definition().await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_flush_container() -> io::Result<()> {
+        use crate::r#async::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_definition().await?;
+        writer.write_file_header(&header).await?;
+
+        writer.write_record(&header, Record::default()).await?;
+        writer.flush_container(&header).await?;
+        writer.flush_container(&header).await?;
+        writer.write_record(&header, Record::default()).await?;
+        writer.shutdown(&header).await?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition().await?;
+        reader.read_file_header().await?;
+
+        let mut container_count = 0;
+
+        while let Some(data_container) = reader.read_data_container().await? {
+            let record_count: usize = data_container
+                .slices()
+                .iter()
+                .map(|slice| slice.header().record_count())
+                .sum();
+
+            assert_eq!(record_count, 1);
+            container_count += 1;
+        }
+
+        assert_eq!(container_count, 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_record_with_records_per_slice_and_slices_per_container() -> io::Result<()> {
+        use std::num::NonZeroUsize;
+
+        use crate::r#async::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::new(2).unwrap())
+            .set_slices_per_container(NonZeroUsize::new(2).unwrap())
+            .build_with_writer(Vec::new());
+
+        writer.write_file_definition().await?;
+        writer.write_file_header(&header).await?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default()).await?;
+        }
+
+        writer.shutdown(&header).await?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition().await?;
+        reader.read_file_header().await?;
+
+        let mut actual = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container().await? {
+            let record_counts: Vec<_> = data_container
+                .slices()
+                .iter()
+                .map(|slice| slice.header().record_count())
+                .collect();
+
+            actual.push(record_counts);
+        }
+
+        assert_eq!(actual, [vec![2, 2], vec![1]]);
+
+        Ok(())
+    }
+}
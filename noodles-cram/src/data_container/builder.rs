@@ -6,8 +6,6 @@ use noodles_sam as sam;
 use super::{slice, CompressionHeader, DataContainer, Slice};
 use crate::{io::writer::Options, Record};
 
-const MAX_SLICE_COUNT: usize = 1;
-
 #[derive(Debug)]
 pub struct Builder {
     slice_builder: slice::Builder,
@@ -42,12 +40,15 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container.get() {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        match self
+            .slice_builder
+            .add_record(record, options.records_per_slice.get())
+        {
             Ok(r) => {
                 self.base_count += u64::try_from(r.read_length())
                     .map_err(AddRecordError::InvalidRecordReadLength)?;
@@ -257,7 +257,7 @@ fn calculate_template_size_chunk(
         features: &Features,
     ) -> Option<Position> {
         alignment_start.and_then(|start| {
-            let span = calculate_alignment_span(read_length, features);
+            let span = calculate_alignment_span(read_length, features)?;
             let end = usize::from(start) + span - 1;
             Position::new(end)
         })
@@ -20,7 +20,6 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
-const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -47,8 +46,12 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
-        if self.records.len() >= MAX_RECORD_COUNT {
+    pub fn add_record(
+        &mut self,
+        record: Record,
+        max_record_count: usize,
+    ) -> Result<&Record, AddRecordError> {
+        if self.records.len() >= max_record_count {
             return Err(AddRecordError::SliceFull(record));
         }
 
@@ -3,7 +3,7 @@ mod header;
 use std::io::{self, BufRead, BufReader, Read};
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use bytes::{Bytes, BytesMut};
+use bytes::{Buf, Bytes, BytesMut};
 use noodles_sam as sam;
 
 use self::header::read_header;
@@ -54,17 +54,7 @@ fn read_sam_header(block: &Block) -> io::Result<sam::Header> {
             let decoder = GzDecoder::new(block.data());
             Box::new(BufReader::new(decoder))
         }
-        compression_method => {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "invalid block compression method: expected {:?} or {:?}, got {:?}",
-                    CompressionMethod::None,
-                    CompressionMethod::Gzip,
-                    compression_method
-                ),
-            ))
-        }
+        _ => Box::new(block.decompressed_data()?.reader()),
     };
 
     let len = reader.read_i32::<LittleEndian>().and_then(|n| {
@@ -152,16 +142,57 @@ mod tests {
     }
 
     #[test]
-    fn test_read_sam_header_with_invalid_compression_method() {
+    fn test_read_sam_header_with_compression_methods() -> io::Result<()> {
+        use crate::codecs::{bzip2, gzip, lzma};
+
+        let raw_header = "@HD\tVN:1.6\n@CO\tnoodles-cram\n";
+
+        let header_data_len = i32::try_from(raw_header.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut data = Vec::new();
+        data.put_i32_le(header_data_len);
+        data.extend(raw_header.as_bytes());
+
+        let expected: sam::Header = raw_header
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        for (compression_method, compressed_data) in [
+            (
+                CompressionMethod::Gzip,
+                gzip::encode(flate2::Compression::default(), &data)?,
+            ),
+            (
+                CompressionMethod::Bzip2,
+                bzip2::encode(::bzip2::Compression::default(), &data)?,
+            ),
+            (CompressionMethod::Lzma, lzma::encode(6, &data)?),
+        ] {
+            let block = Block::builder()
+                .set_compression_method(compression_method)
+                .set_content_type(ContentType::FileHeader)
+                .set_uncompressed_len(data.len())
+                .set_data(compressed_data.into())
+                .build();
+
+            let actual = read_sam_header(&block)?;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sam_header_with_invalid_compressed_data() {
         let block = Block::builder()
             .set_compression_method(CompressionMethod::Lzma)
             .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(4)
+            .set_data(vec![0x00, 0x00, 0x00, 0x00].into())
             .build();
 
-        assert!(matches!(
-            read_sam_header(&block),
-            Err(e) if e.kind() == io::ErrorKind::InvalidData
-        ));
+        assert!(read_sam_header(&block).is_err());
     }
 
     #[test]
@@ -133,6 +133,8 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
+        self.options.validate_version()?;
+
         let file_definition = FileDefinition::new(self.options.version, Default::default());
         write_file_definition(&mut self.inner, &file_definition)
     }
@@ -218,7 +220,10 @@ where
         use crate::data_container::builder::AddRecordError;
 
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -335,6 +340,25 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_write_file_definition_with_version() -> io::Result<()> {
+        let mut writer = Builder::default()
+            .set_version(Version::new(3, 1))
+            .build_with_writer(Vec::new());
+        writer.write_file_definition()?;
+        assert_eq!(&writer.get_ref()[..6], [b'C', b'R', b'A', b'M', 0x03, 0x01]);
+
+        let mut writer = Builder::default()
+            .set_version(Version::new(4, 0))
+            .build_with_writer(Vec::new());
+        assert!(matches!(
+            writer.write_file_definition(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_missing_reference_sequence_checksums() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
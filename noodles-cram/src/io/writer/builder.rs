@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{self, Write},
+    num::NonZeroUsize,
     path::Path,
 };
 
@@ -17,6 +18,7 @@ use crate::{
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    version: Option<Version>,
 }
 
 impl Builder {
@@ -91,6 +93,55 @@ impl Builder {
         self
     }
 
+    /// Sets the CRAM format version to write.
+    ///
+    /// By default, the version is CRAM 3.0, or CRAM 3.1 if the block content-encoder map uses
+    /// CRAM 3.1 codecs. The writer supports CRAM 3.0 and 3.1. The version is validated when the
+    /// file definition is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{file_definition::Version, io::writer::Builder};
+    /// let builder = Builder::default().set_version(Version::new(3, 1));
+    /// ```
+    pub fn set_version(mut self, version: Version) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the maximum number of records per slice.
+    ///
+    /// The default is 10240.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_records_per_slice(NonZeroUsize::new(1024).unwrap());
+    /// ```
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_slices_per_container(NonZeroUsize::new(4).unwrap());
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -119,7 +170,9 @@ impl Builder {
     where
         W: Write,
     {
-        if uses_cram_3_1_codecs(&self.options.block_content_encoder_map) {
+        if let Some(version) = self.version {
+            self.options.version = version;
+        } else if uses_cram_3_1_codecs(&self.options.block_content_encoder_map) {
             self.options.version = Version::new(3, 1);
         }
 
@@ -1,11 +1,23 @@
+use std::{io, num::NonZeroUsize};
+
+use super::builder::uses_cram_3_1_codecs;
 use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
 
+const DEFAULT_RECORDS_PER_SLICE: NonZeroUsize = match NonZeroUsize::new(10240) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+const DEFAULT_SLICES_PER_CONTAINER: NonZeroUsize = NonZeroUsize::MIN;
+
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
+    pub records_per_slice: NonZeroUsize,
+    pub slices_per_container: NonZeroUsize,
 }
 
 impl Default for Options {
@@ -15,6 +27,36 @@ impl Default for Options {
             encode_alignment_start_positions_as_deltas: true,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
+            records_per_slice: DEFAULT_RECORDS_PER_SLICE,
+            slices_per_container: DEFAULT_SLICES_PER_CONTAINER,
         }
     }
 }
+
+impl Options {
+    /// Checks that the version is supported by the writer and its block content-encoder map.
+    pub fn validate_version(&self) -> io::Result<()> {
+        let cram_3_0 = Version::new(3, 0);
+        let cram_3_1 = Version::new(3, 1);
+
+        if self.version != cram_3_0 && self.version != cram_3_1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "unsupported CRAM version: expected 3.0 or 3.1, got {}.{}",
+                    self.version.major(),
+                    self.version.minor()
+                ),
+            ));
+        }
+
+        if self.version == cram_3_0 && uses_cram_3_1_codecs(&self.block_content_encoder_map) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CRAM 3.0 does not support the CRAM 3.1 codecs in the block content-encoder map",
+            ));
+        }
+
+        Ok(())
+    }
+}
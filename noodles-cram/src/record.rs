@@ -9,7 +9,10 @@ mod next_mate_flags;
 pub mod resolve;
 
 pub use self::{
-    builder::Builder, feature::Feature, features::Features, flags::Flags,
+    builder::Builder,
+    feature::Feature,
+    features::{Features, PositionOverflowError},
+    flags::Flags,
     next_mate_flags::NextMateFlags,
 };
 
@@ -104,14 +107,42 @@ impl Record {
     }
 
     /// Returns the alignment span.
-    fn alignment_span(&self) -> usize {
+    fn alignment_span(&self) -> Option<usize> {
         calculate_alignment_span(self.read_length(), self.features())
     }
 
     /// Returns the alignment end.
+    ///
+    /// This returns `None` if the record is unmapped or if the alignment span is invalid, i.e.,
+    /// the read length is less than the number of inserted and soft clipped bases, or the span or
+    /// end overflows (see [`Features::reference_span`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::{self as cram, record::{Feature, Features}};
+    ///
+    /// let record = cram::Record::builder()
+    ///     .set_read_length(4)
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .build();
+    /// assert_eq!(record.alignment_end(), Position::new(11));
+    ///
+    /// let record = cram::Record::builder()
+    ///     .set_read_length(1)
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_features(Features::from(vec![Feature::SoftClip(Position::MIN, vec![b'A', b'C'])]))
+    ///     .build();
+    /// assert!(record.alignment_end().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
     pub fn alignment_end(&self) -> Option<Position> {
         self.alignment_start().and_then(|alignment_start| {
-            let end = usize::from(alignment_start) + self.alignment_span() - 1;
+            let end = usize::from(alignment_start)
+                .checked_add(self.alignment_span()?)?
+                .checked_sub(1)?;
+
             Position::new(end)
         })
     }
@@ -330,17 +361,8 @@ impl sam::alignment::Record for Record {
     }
 }
 
-pub(crate) fn calculate_alignment_span(read_length: usize, features: &Features) -> usize {
-    features
-        .iter()
-        .fold(read_length, |alignment_span, feature| match feature {
-            Feature::Insertion(_, bases) => alignment_span - bases.len(),
-            Feature::InsertBase(_, _) => alignment_span - 1,
-            Feature::Deletion(_, len) => alignment_span + len,
-            Feature::ReferenceSkip(_, len) => alignment_span + len,
-            Feature::SoftClip(_, bases) => alignment_span - bases.len(),
-            _ => alignment_span,
-        })
+pub(crate) fn calculate_alignment_span(read_length: usize, features: &Features) -> Option<usize> {
+    features.reference_span(read_length)
 }
 
 fn get_reference_sequence(
@@ -364,10 +386,10 @@ mod tests {
     #[test]
     fn test_calculate_alignment_span() -> Result<(), noodles_core::position::TryFromIntError> {
         let features = Features::default();
-        assert_eq!(calculate_alignment_span(4, &features), 4);
+        assert_eq!(calculate_alignment_span(4, &features), Some(4));
 
         let features = Features::from(vec![Feature::HardClip(Position::try_from(1)?, 4)]);
-        assert_eq!(calculate_alignment_span(4, &features), 4);
+        assert_eq!(calculate_alignment_span(4, &features), Some(4));
 
         let features = Features::from(vec![
             Feature::Insertion(Position::try_from(1)?, vec![b'A', b'C']),
@@ -376,7 +398,47 @@ mod tests {
             Feature::ReferenceSkip(Position::try_from(10)?, 5),
             Feature::SoftClip(Position::try_from(16)?, vec![b'A', b'C', b'G', b'T']),
         ]);
-        assert_eq!(calculate_alignment_span(20, &features), 21);
+        assert_eq!(calculate_alignment_span(20, &features), Some(21));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_alignment_end() -> Result<(), noodles_core::position::TryFromIntError> {
+        let record = Record::builder()
+            .set_read_length(4)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_features(Features::from(vec![Feature::Deletion(
+                Position::try_from(3)?,
+                2,
+            )]))
+            .build();
+        assert_eq!(record.alignment_end(), Some(Position::try_from(13)?));
+
+        let record = Record::builder().set_read_length(4).build();
+        assert!(record.alignment_end().is_none());
+
+        // The read length is less than the number of soft clipped bases.
+        let record = Record::builder()
+            .set_read_length(1)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_features(Features::from(vec![Feature::SoftClip(
+                Position::MIN,
+                vec![b'A', b'C'],
+            )]))
+            .build();
+        assert!(record.alignment_end().is_none());
+
+        // The end overflows.
+        let record = Record::builder()
+            .set_read_length(4)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_features(Features::from(vec![Feature::Deletion(
+                Position::try_from(3)?,
+                usize::MAX - 8,
+            )]))
+            .build();
+        assert!(record.alignment_end().is_none());
 
         Ok(())
     }
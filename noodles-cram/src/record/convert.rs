@@ -6,6 +6,9 @@ use super::{Features, Flags, QualityScores, Record, Sequence};
 
 impl Record {
     /// Converts an alignment record to a CRAM record.
+    ///
+    /// This returns an error if the reference sequence ID or mate reference sequence ID is not in
+    /// the header reference sequence dictionary.
     pub fn try_from_alignment_record<R>(header: &sam::Header, record: &R) -> io::Result<Self>
     where
         R: sam::alignment::Record + ?Sized,
@@ -18,6 +21,7 @@ impl Record {
         let mut flags = Flags::default();
 
         if let Some(reference_sequence_id) = record.reference_sequence_id(header).transpose()? {
+            validate_reference_sequence_id(header, reference_sequence_id)?;
             builder = builder.set_reference_sequence_id(reference_sequence_id);
         }
 
@@ -43,6 +47,7 @@ impl Record {
         if let Some(mate_reference_sequence_id) =
             record.mate_reference_sequence_id(header).transpose()?
         {
+            validate_reference_sequence_id(header, mate_reference_sequence_id)?;
             builder = builder.set_next_fragment_reference_sequence_id(mate_reference_sequence_id);
         }
 
@@ -224,6 +229,24 @@ where
     Ok(buf)
 }
 
+fn validate_reference_sequence_id(
+    header: &sam::Header,
+    reference_sequence_id: usize,
+) -> io::Result<()> {
+    let reference_sequence_count = header.reference_sequences().len();
+
+    if reference_sequence_id < reference_sequence_count {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid reference sequence ID: expected < {reference_sequence_count}, got {reference_sequence_id}"
+            ),
+        ))
+    }
+}
+
 fn get_read_group_id(
     read_groups: &sam::header::ReadGroups,
     data: &sam::alignment::record_buf::Data,
@@ -268,3 +291,50 @@ fn maybe_insert_read_group(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_try_from_alignment_record_with_reference_sequence_id() -> io::Result<()> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8).unwrap()),
+            )
+            .build();
+
+        let record = sam::alignment::RecordBuf::builder()
+            .set_reference_sequence_id(0)
+            .build();
+        let actual = Record::try_from_alignment_record(&header, &record)?;
+        assert_eq!(actual.reference_sequence_id(), Some(0));
+
+        let record = sam::alignment::RecordBuf::builder()
+            .set_reference_sequence_id(1)
+            .build();
+        assert!(matches!(
+            Record::try_from_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = sam::alignment::RecordBuf::builder()
+            .set_mate_reference_sequence_id(1)
+            .build();
+        assert!(matches!(
+            Record::try_from_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = sam::alignment::RecordBuf::default();
+        let actual = Record::try_from_alignment_record(&header, &record)?;
+        assert!(actual.reference_sequence_id().is_none());
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 use std::{error, fmt};
 
 /// A CRAM record feature kind.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Code {
     /// A stretch of bases (`b`).
     Bases,
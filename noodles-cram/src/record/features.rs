@@ -1,10 +1,17 @@
 mod cigar;
+mod quality;
+mod trim;
+mod visualize;
 mod with_positions;
+mod with_reference_positions;
 
-pub use self::{cigar::Cigar, with_positions::WithPositions};
+pub use self::{
+    cigar::Cigar, with_positions::WithPositions, with_reference_positions::WithReferencePositions,
+};
 
 use std::{
-    io,
+    collections::HashMap,
+    error, fmt, io,
     ops::{Deref, DerefMut},
     slice,
 };
@@ -12,10 +19,13 @@ use std::{
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
-    alignment::record_buf::{QualityScores, Sequence},
+    alignment::{
+        record::cigar::Op,
+        record_buf::{QualityScores, Sequence},
+    },
 };
 
-use super::{Feature, Flags};
+use super::{feature::Code, Feature, Flags};
 
 /// CRAM record features.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -32,7 +42,116 @@ impl Features {
         cigar_to_features(flags, cigar, sequence, quality_scores)
     }
 
+    /// Converts a list of CIGAR operation kinds and lengths to CRAM record features.
+    ///
+    /// This is a convenience wrapper around [`Self::from_cigar`] using default flags.
+    ///
+    /// This returns an error if the sequence length does not match the number of read bases
+    /// consumed by the operations or if the quality scores are neither empty nor the same length
+    /// as the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::op::Kind,
+    ///     record_buf::{QualityScores, Sequence},
+    /// };
+    ///
+    /// let sequence = Sequence::from(b"AC");
+    /// let quality_scores = QualityScores::from(vec![45, 35]);
+    /// let features = Features::from_ops(&[(Kind::Match, 2)], &sequence, &quality_scores)?;
+    ///
+    /// assert_eq!(
+    ///     features,
+    ///     Features::from(vec![
+    ///         Feature::Bases(Position::MIN, vec![b'A', b'C']),
+    ///         Feature::Scores(Position::MIN, vec![45, 35]),
+    ///     ])
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_ops(
+        ops: &[(sam::alignment::record::cigar::op::Kind, usize)],
+        sequence: &Sequence,
+        quality_scores: &QualityScores,
+    ) -> io::Result<Self> {
+        let cigar = ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect();
+        Self::try_from_cigar(Flags::default(), &cigar, sequence, quality_scores)
+    }
+
+    /// Converts SAM record CIGAR operations to CRAM record features, checking the input.
+    ///
+    /// Unlike [`Self::from_cigar`], this does not panic on malformed input.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the read position overflows (see [`PositionOverflowError`]), the
+    /// sequence length does not match the number of read bases consumed by the operations, or the
+    /// quality scores are neither empty nor the same length as the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::record::{Features, Flags};
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::{Cigar, QualityScores, Sequence},
+    /// };
+    ///
+    /// let cigar: Cigar = [Op::new(Kind::Match, usize::MAX)].into_iter().collect();
+    /// let sequence = Sequence::default();
+    /// let quality_scores = QualityScores::default();
+    ///
+    /// assert!(Features::try_from_cigar(Flags::default(), &cigar, &sequence, &quality_scores).is_err());
+    /// ```
+    pub fn try_from_cigar(
+        flags: Flags,
+        cigar: &sam::alignment::record_buf::Cigar,
+        sequence: &Sequence,
+        quality_scores: &QualityScores,
+    ) -> io::Result<Self> {
+        let end = cigar
+            .as_ref()
+            .iter()
+            .try_fold(Position::MIN, |position, &op| advance(position, op))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let read_length = usize::from(end) - usize::from(Position::MIN);
+
+        if sequence.len() != read_length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sequence length mismatch: expected {read_length}, got {}",
+                    sequence.len()
+                ),
+            ));
+        }
+
+        if !quality_scores.is_empty() && quality_scores.as_ref().len() != read_length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "quality scores length mismatch: expected {read_length}, got {}",
+                    quality_scores.as_ref().len()
+                ),
+            ));
+        }
+
+        try_cigar_to_features(flags, cigar, sequence, quality_scores)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+
     /// Converts CRAM features to SAM CIGAR operations.
+    ///
+    /// Features are walked in read position order. Bases, read bases, and substitutions are
+    /// treated as matches, and consecutive operations of the same kind are merged.
+    ///
+    /// This returns an error if a hard clip is not at either end of the alignment or if a soft
+    /// clip is not between a hard clip or an end and the rest of the alignment.
     pub fn try_into_cigar(
         &self,
         read_length: usize,
@@ -61,6 +180,8 @@ impl Features {
             }
 
             let (kind, len) = match feature {
+                Feature::Bases(_, bases) => (Kind::Match, bases.len()),
+                Feature::ReadBase(..) => (Kind::Match, 1),
                 Feature::Substitution(..) => (Kind::Match, 1),
                 Feature::Insertion(_, bases) => (Kind::Insertion, bases.len()),
                 Feature::Deletion(_, len) => (Kind::Deletion, *len),
@@ -69,7 +190,7 @@ impl Features {
                 Feature::SoftClip(_, bases) => (Kind::SoftClip, bases.len()),
                 Feature::Padding(_, len) => (Kind::Pad, *len),
                 Feature::HardClip(_, len) => (Kind::HardClip, *len),
-                _ => continue,
+                Feature::Scores(..) | Feature::QualityScore(..) => continue,
             };
 
             merge_or_insert_op(&mut ops, kind, len);
@@ -86,6 +207,8 @@ impl Features {
             merge_or_insert_op(&mut ops, Kind::Match, len);
         }
 
+        validate_clips(&ops)?;
+
         Ok(ops
             .into_iter()
             .map(|(kind, len)| Op::new(kind, len))
@@ -103,6 +226,385 @@ impl Features {
     ) -> WithPositions<'_, slice::Iter<'_, Feature>> {
         WithPositions::new(self.iter(), alignment_start)
     }
+
+    /// Returns an iterator over features with their reference positions.
+    ///
+    /// The reference position starts at the given alignment start and advances over matches,
+    /// deletions, and reference skips. It does not advance over insertions or clips.
+    pub fn with_reference_positions(
+        &self,
+        alignment_start: Position,
+    ) -> WithReferencePositions<'_> {
+        WithReferencePositions::new(&self.0, alignment_start)
+    }
+
+    /// Attaches quality scores to the bases at the given read position.
+    ///
+    /// This inserts a [`Feature::Scores`] immediately after the [`Feature::Bases`] at `position`,
+    /// replacing any scores already attached to it.
+    ///
+    /// This returns an error if there are no bases at the given position or if the number of
+    /// scores does not match the number of bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let mut features = Features::from(vec![Feature::Bases(Position::MIN, vec![b'A', b'C'])]);
+    /// features.attach_scores(Position::MIN, vec![45, 35])?;
+    ///
+    /// assert_eq!(
+    ///     features,
+    ///     Features::from(vec![
+    ///         Feature::Bases(Position::MIN, vec![b'A', b'C']),
+    ///         Feature::Scores(Position::MIN, vec![45, 35]),
+    ///     ])
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn attach_scores(&mut self, position: Position, scores: Vec<u8>) -> io::Result<()> {
+        let (i, bases_len) = self
+            .iter()
+            .enumerate()
+            .find_map(|(i, feature)| match feature {
+                Feature::Bases(p, bases) if *p == position => Some((i, bases.len())),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing bases at position {position}"),
+                )
+            })?;
+
+        if scores.len() != bases_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "scores length mismatch: expected {bases_len}, got {}",
+                    scores.len()
+                ),
+            ));
+        }
+
+        let feature = Feature::Scores(position, scores);
+
+        match self.get_mut(i + 1) {
+            Some(Feature::Scores(p, _)) if *p == position => self[i + 1] = feature,
+            _ => self.insert(i + 1, feature),
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over matched read and reference position pairs.
+    ///
+    /// Each pair is a 1-based read position and its aligned reference position, starting at the
+    /// given alignment start. Insertions, deletions, reference skips, clips, and padding do not
+    /// yield pairs. Bases after the last feature are matches up to the read length.
+    ///
+    /// The iterator ends early if a read or reference position overflows, e.g., from a malformed
+    /// deletion length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::Features;
+    ///
+    /// let features = Features::default();
+    /// let alignment_start = Position::try_from(5)?;
+    /// let pairs: Vec<_> = features.matched_pairs(alignment_start, 2).collect();
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     [
+    ///         (Position::try_from(1)?, Position::try_from(5)?),
+    ///         (Position::try_from(2)?, Position::try_from(6)?),
+    ///     ]
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn matched_pairs(
+        &self,
+        alignment_start: Position,
+        read_length: usize,
+    ) -> impl Iterator<Item = (Position, Position)> + '_ {
+        use sam::alignment::record::cigar::op::Kind;
+
+        fn advance(position: Position, len: usize, is_consumed: bool) -> Option<Position> {
+            if is_consumed {
+                position.checked_add(len)
+            } else {
+                Some(position)
+            }
+        }
+
+        let mut positions = Some((Position::MIN, alignment_start));
+
+        self.cigar(read_length)
+            .map_while(move |op| {
+                let (read_start, reference_start) = positions?;
+                let (kind, len) = (op.kind(), op.len());
+
+                positions = advance(read_start, len, kind.consumes_read()).zip(advance(
+                    reference_start,
+                    len,
+                    kind.consumes_reference(),
+                ));
+
+                let n = match kind {
+                    Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => len,
+                    _ => 0,
+                };
+
+                Some((0..n).map_while(move |i| {
+                    read_start
+                        .checked_add(i)
+                        .zip(reference_start.checked_add(i))
+                }))
+            })
+            .flatten()
+    }
+
+    /// Returns the fraction of aligned bases that match the reference.
+    ///
+    /// Aligned bases are read bases in alignment matches, i.e., implicit matches and
+    /// substitutions. Each [`Feature::Substitution`] is counted as a mismatch.
+    ///
+    /// This returns `None` if the read has no aligned bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{feature::substitution, Feature, Features};
+    ///
+    /// let features = Features::from(vec![Feature::Substitution(
+    ///     Position::try_from(2)?,
+    ///     substitution::Value::Code(0),
+    /// )]);
+    ///
+    /// assert_eq!(features.identity(4), Some(0.75));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn identity(&self, read_length: usize) -> Option<f64> {
+        use sam::alignment::record::cigar::op::Kind;
+
+        let aligned_base_count: usize = self
+            .cigar(read_length)
+            .filter(|op| {
+                matches!(
+                    op.kind(),
+                    Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch
+                )
+            })
+            .map(|op| op.len())
+            .sum();
+
+        if aligned_base_count == 0 {
+            return None;
+        }
+
+        let mismatch_count = self
+            .iter()
+            .filter(|feature| matches!(feature, Feature::Substitution(..)))
+            .count();
+
+        let match_count = aligned_base_count.saturating_sub(mismatch_count);
+
+        Some(match_count as f64 / aligned_base_count as f64)
+    }
+
+    /// Returns the mean quality score of matched read positions.
+    ///
+    /// Quality scores are taken from [`Feature::ReadBase`], [`Feature::Scores`], and
+    /// [`Feature::QualityScore`] features. Positions in insertions and soft clips are excluded.
+    ///
+    /// This returns `None` if no matched read position has a quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let mut features = Features::from(vec![Feature::Bases(Position::MIN, vec![b'A', b'C'])]);
+    /// assert!(features.mean_matched_quality().is_none());
+    ///
+    /// features.attach_scores(Position::MIN, vec![30, 40])?;
+    /// assert_eq!(features.mean_matched_quality(), Some(35.0));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn mean_matched_quality(&self) -> Option<f64> {
+        quality::mean_matched_quality(&self.0)
+    }
+
+    /// Soft clips low-quality bases from the read ends.
+    ///
+    /// Starting from each end of the read (after any existing soft clip), aligned bases with a
+    /// quality score less than `min_score` are converted to soft clipped bases. These are read
+    /// bases ([`Feature::ReadBase`]) and the ends of runs of bases ([`Feature::Bases`]) with
+    /// quality scores ([`Feature::Scores`]). Trimming at an end stops at the first base that is
+    /// not a low-quality aligned base. The quality scores of the trimmed bases are kept as
+    /// [`Feature::QualityScore`] features.
+    ///
+    /// Deletions and reference skips that would border the new soft clips are removed.
+    ///
+    /// This returns the number of bases trimmed from the start and end of the read and the number
+    /// of reference bases the alignment start moves forward by, respectively. The last includes
+    /// the lengths of the removed deletions and reference skips at the start of the read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let mut features = Features::from(vec![Feature::ReadBase(Position::MIN, b'A', 2)]);
+    /// assert_eq!(features.trim_low_quality_ends(4, 10), (1, 0, 1));
+    ///
+    /// assert_eq!(
+    ///     features,
+    ///     Features::from(vec![
+    ///         Feature::SoftClip(Position::MIN, vec![b'A']),
+    ///         Feature::QualityScore(Position::MIN, 2),
+    ///     ])
+    /// );
+    /// ```
+    pub fn trim_low_quality_ends(
+        &mut self,
+        read_length: usize,
+        min_score: u8,
+    ) -> (usize, usize, usize) {
+        trim::trim_low_quality_ends(&mut self.0, read_length, min_score)
+    }
+
+    /// Removes the first `n` read bases from the features.
+    ///
+    /// Read positions are shifted by `n`. Features that fall entirely in the trimmed bases are
+    /// removed, and features with bases (e.g., soft clips and insertions) that span the new start
+    /// are truncated. A leading hard clip is kept.
+    ///
+    /// This returns the number of reference bases consumed by the trimmed bases, i.e., the aligned
+    /// bases and the deletions and reference skips in the trimmed bases. The alignment start moves
+    /// forward by this length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let mut features = Features::from(vec![
+    ///     Feature::ReadBase(Position::try_from(1)?, b'A', 2),
+    ///     Feature::Deletion(Position::try_from(5)?, 3),
+    /// ]);
+    ///
+    /// assert_eq!(features.trim_front(2), 2);
+    ///
+    /// assert_eq!(
+    ///     features,
+    ///     Features::from(vec![Feature::Deletion(Position::try_from(3)?, 3)])
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn trim_front(&mut self, n: usize) -> usize {
+        trim::trim_front(&mut self.0, n)
+    }
+
+    /// Counts the features by kind.
+    ///
+    /// Each feature is keyed by its [`Code`]. Kinds that do not occur are not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{feature::Code, Feature, Features};
+    ///
+    /// let features = Features::from(vec![
+    ///     Feature::Deletion(Position::try_from(3)?, 2),
+    ///     Feature::Deletion(Position::try_from(8)?, 1),
+    ///     Feature::InsertBase(Position::try_from(13)?, b'A'),
+    /// ]);
+    ///
+    /// let counts = features.count_by_kind();
+    /// assert_eq!(counts.get(&Code::Deletion), Some(&2));
+    /// assert_eq!(counts.get(&Code::InsertBase), Some(&1));
+    /// assert!(counts.get(&Code::Insertion).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn count_by_kind(&self) -> HashMap<Code, usize> {
+        let mut counts = HashMap::new();
+
+        for feature in &self.0 {
+            *counts.entry(feature.code()).or_default() += 1;
+        }
+
+        counts
+    }
+
+    /// Returns the number of reference bases consumed by the alignment.
+    ///
+    /// Read bases not covered by features are implicit matches, so the read length is required.
+    /// Insertions and soft clips do not consume reference bases, whereas deletions and reference
+    /// skips do.
+    ///
+    /// This returns `None` if the read length is less than the number of inserted and soft clipped
+    /// bases or if the span overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let features = Features::from(vec![
+    ///     Feature::Deletion(Position::try_from(3)?, 2),
+    ///     Feature::ReferenceSkip(Position::try_from(5)?, 10),
+    /// ]);
+    ///
+    /// assert_eq!(features.reference_span(8), Some(20));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn reference_span(&self, read_length: usize) -> Option<usize> {
+        self.0
+            .iter()
+            .try_fold(read_length, |span, feature| match feature {
+                Feature::Insertion(_, bases) => span.checked_sub(bases.len()),
+                Feature::InsertBase(_, _) => span.checked_sub(1),
+                Feature::Deletion(_, len) => span.checked_add(*len),
+                Feature::ReferenceSkip(_, len) => span.checked_add(*len),
+                Feature::SoftClip(_, bases) => span.checked_sub(bases.len()),
+                _ => Some(span),
+            })
+    }
+
+    /// Renders the features as a two-line alignment diagram for debugging.
+    ///
+    /// The first line is the reference, where each aligned column is the last digit of its
+    /// reference position. The second line is the read, where `=` is an implicit match, `-` is a
+    /// gap, `~` is a reference skip, `*` is padding, and lowercase bases are soft clipped. Bases
+    /// from substitution codes are unknown without the reference and are rendered as `X`. Hard
+    /// clips are not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{Feature, Features};
+    ///
+    /// let features = Features::from(vec![Feature::Deletion(Position::MIN, 1)]);
+    /// let alignment_start = Position::try_from(8)?;
+    /// assert_eq!(features.visualize(alignment_start, 2), "890\n-==");
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn visualize(&self, alignment_start: Position, read_length: usize) -> String {
+        visualize::visualize(&self.0, alignment_start, read_length)
+    }
 }
 
 impl Deref for Features {
@@ -125,33 +627,109 @@ impl From<Vec<Feature>> for Features {
     }
 }
 
+/// An error returned when a CIGAR operation overflows the read position.
+///
+/// This is wrapped in an [`io::Error`] of kind [`io::ErrorKind::InvalidInput`] and can be
+/// retrieved using [`io::Error::get_ref`] and downcasting.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PositionOverflowError {
+    op: Op,
+}
+
+impl PositionOverflowError {
+    /// Returns the CIGAR operation that overflowed the read position.
+    pub fn op(&self) -> Op {
+        self.op
+    }
+}
+
+impl error::Error for PositionOverflowError {}
+
+impl fmt::Display for PositionOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "read position overflow: {:?} operation with length {}",
+            self.op.kind(),
+            self.op.len()
+        )
+    }
+}
+
+fn validate_clips(ops: &[(sam::alignment::record::cigar::op::Kind, usize)]) -> io::Result<()> {
+    use sam::alignment::record::cigar::op::Kind;
+
+    fn is_clip(kind: Kind) -> bool {
+        matches!(kind, Kind::SoftClip | Kind::HardClip)
+    }
+
+    let last_index = ops.len().saturating_sub(1);
+
+    for (i, (kind, _)) in ops.iter().enumerate() {
+        let is_valid = match kind {
+            Kind::HardClip => i == 0 || i == last_index,
+            Kind::SoftClip => {
+                ops[..i].iter().all(|(k, _)| is_clip(*k))
+                    || ops[i + 1..].iter().all(|(k, _)| is_clip(*k))
+            }
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid {kind:?} position in CIGAR"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn cigar_to_features(
     flags: Flags,
     cigar: &sam::alignment::record_buf::Cigar,
     sequence: &Sequence,
     quality_scores: &QualityScores,
 ) -> Features {
+    try_cigar_to_features(flags, cigar, sequence, quality_scores)
+        .expect("attempt to add with overflow")
+}
+
+fn try_cigar_to_features(
+    flags: Flags,
+    cigar: &sam::alignment::record_buf::Cigar,
+    sequence: &Sequence,
+    quality_scores: &QualityScores,
+) -> Result<Features, PositionOverflowError> {
     use sam::alignment::record::cigar::op::Kind;
 
     let mut features = Features::default();
     let mut read_position = Position::MIN;
 
-    for op in cigar.as_ref().iter() {
+    // Score features are only written when quality scores are present and not stored as an array.
+    let has_scores = !quality_scores.is_empty();
+    let write_scores = has_scores && !flags.are_quality_scores_stored_as_array();
+
+    for &op in cigar.as_ref().iter() {
+        // Zero-length operations are meaningless and would otherwise produce empty features.
+        if op.is_empty() {
+            continue;
+        }
+
         match op.kind() {
             Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
-                if op.len() == 1 {
+                if op.len() == 1 && has_scores {
                     let base = sequence[read_position];
                     let score = quality_scores[read_position];
                     features.push(Feature::ReadBase(read_position, base, score));
                 } else {
-                    let end = read_position
-                        .checked_add(op.len())
-                        .expect("attempt to add with overflow");
+                    let end = advance(read_position, op)?;
 
                     let bases = sequence[read_position..end].to_vec();
                     features.push(Feature::Bases(read_position, bases));
 
-                    if !flags.are_quality_scores_stored_as_array() {
+                    if write_scores {
                         let scores = quality_scores[read_position..end].to_vec();
                         features.push(Feature::Scores(read_position, scores));
                     }
@@ -162,19 +740,17 @@ fn cigar_to_features(
                     let base = sequence[read_position];
                     features.push(Feature::InsertBase(read_position, base));
 
-                    if !flags.are_quality_scores_stored_as_array() {
+                    if write_scores {
                         let score = quality_scores[read_position];
                         features.push(Feature::QualityScore(read_position, score));
                     }
                 } else {
-                    let end = read_position
-                        .checked_add(op.len())
-                        .expect("attempt to add with overflow");
+                    let end = advance(read_position, op)?;
 
                     let bases = sequence[read_position..end].to_vec();
                     features.push(Feature::Insertion(read_position, bases));
 
-                    if !flags.are_quality_scores_stored_as_array() {
+                    if write_scores {
                         let scores = quality_scores[read_position..end].to_vec();
                         features.push(Feature::Scores(read_position, scores));
                     }
@@ -183,15 +759,13 @@ fn cigar_to_features(
             Kind::Deletion => features.push(Feature::Deletion(read_position, op.len())),
             Kind::Skip => features.push(Feature::ReferenceSkip(read_position, op.len())),
             Kind::SoftClip => {
-                let end = read_position
-                    .checked_add(op.len())
-                    .expect("attempt to add with overflow");
+                let end = advance(read_position, op)?;
 
                 let bases = &sequence[read_position..end];
 
                 features.push(Feature::SoftClip(read_position, bases.to_vec()));
 
-                if !flags.are_quality_scores_stored_as_array() {
+                if write_scores {
                     if bases.len() == 1 {
                         let score = quality_scores[read_position];
                         features.push(Feature::QualityScore(read_position, score));
@@ -205,14 +779,23 @@ fn cigar_to_features(
             Kind::Pad => features.push(Feature::Padding(read_position, op.len())),
         };
 
-        if op.kind().consumes_read() {
-            read_position = read_position
-                .checked_add(op.len())
-                .expect("attempt to add with overflow");
-        }
+        read_position = advance(read_position, op)?;
     }
 
-    features
+    Ok(features)
+}
+
+/// Advances a read position past a CIGAR operation.
+///
+/// The position is unchanged if the operation does not consume the read.
+fn advance(position: Position, op: Op) -> Result<Position, PositionOverflowError> {
+    if op.kind().consumes_read() {
+        position
+            .checked_add(op.len())
+            .ok_or(PositionOverflowError { op })
+    } else {
+        Ok(position)
+    }
 }
 
 #[cfg(test)]
@@ -346,6 +929,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cigar_to_features_with_zero_length_ops() -> Result<(), Box<dyn std::error::Error>> {
+        let flags = Flags::default();
+
+        let cigar = [Op::new(Kind::Match, 0)].into_iter().collect();
+        let sequence = Sequence::default();
+        let quality_scores = QualityScores::default();
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        assert!(actual.is_empty());
+
+        let cigar = [
+            Op::new(Kind::SoftClip, 0),
+            Op::new(Kind::Match, 0),
+            Op::new(Kind::Insertion, 0),
+            Op::new(Kind::Match, 1),
+        ]
+        .into_iter()
+        .collect();
+        let sequence = Sequence::from(b"A");
+        let quality_scores = QualityScores::from(vec![45]);
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![Feature::ReadBase(Position::try_from(1)?, b'A', 45)]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_cigar_to_features_with_quality_scores_stored_as_array(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -467,6 +1077,250 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_matched_pairs() -> Result<(), Box<dyn std::error::Error>> {
+        let alignment_start = Position::try_from(5)?;
+
+        let features = Features::default();
+        let actual: Vec<_> = features.matched_pairs(alignment_start, 2).collect();
+        let expected = [
+            (Position::try_from(1)?, Position::try_from(5)?),
+            (Position::try_from(2)?, Position::try_from(6)?),
+        ];
+        assert_eq!(actual, expected);
+
+        // 1S1M1I1D1M
+        let features = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A']),
+            Feature::Insertion(Position::try_from(3)?, vec![b'C']),
+            Feature::Deletion(Position::try_from(4)?, 1),
+        ]);
+        let actual: Vec<_> = features.matched_pairs(alignment_start, 4).collect();
+        let expected = [
+            (Position::try_from(2)?, Position::try_from(5)?),
+            (Position::try_from(4)?, Position::try_from(7)?),
+        ];
+        assert_eq!(actual, expected);
+
+        // 1M1D1M with an overflowing deletion length
+        let features = Features::from(vec![Feature::Deletion(Position::try_from(2)?, usize::MAX)]);
+        let actual: Vec<_> = features.matched_pairs(alignment_start, 2).collect();
+        let expected = [(Position::try_from(1)?, Position::try_from(5)?)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ops() -> io::Result<()> {
+        let sequence = Sequence::from(b"AC");
+        let quality_scores = QualityScores::from(vec![45, 35]);
+
+        let actual = Features::from_ops(&[(Kind::Match, 2)], &sequence, &quality_scores)?;
+        let cigar = [Op::new(Kind::Match, 2)].into_iter().collect();
+        let expected = Features::from_cigar(Flags::default(), &cigar, &sequence, &quality_scores);
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            Features::from_ops(&[(Kind::Match, 3)], &sequence, &quality_scores),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let quality_scores = QualityScores::from(vec![45]);
+        assert!(matches!(
+            Features::from_ops(&[(Kind::Match, 2)], &sequence, &quality_scores),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ops_with_empty_quality_scores() -> io::Result<()> {
+        let sequence = Sequence::from(b"A");
+        let quality_scores = QualityScores::default();
+
+        let actual = Features::from_ops(&[(Kind::Match, 1)], &sequence, &quality_scores)?;
+        let expected = Features::from(vec![Feature::Bases(Position::MIN, vec![b'A'])]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_cigar() -> io::Result<()> {
+        let flags = Flags::default();
+        let sequence = Sequence::from(b"AC");
+        let quality_scores = QualityScores::from(vec![45, 35]);
+
+        let cigar = [Op::new(Kind::Match, 2)].into_iter().collect();
+        let actual = Features::try_from_cigar(flags, &cigar, &sequence, &quality_scores)?;
+        let expected = Features::from_cigar(flags, &cigar, &sequence, &quality_scores);
+        assert_eq!(actual, expected);
+
+        let op = Op::new(Kind::Match, usize::from(Position::MAX));
+        let cigar = [Op::new(Kind::SoftClip, 1), op].into_iter().collect();
+        let result = Features::try_from_cigar(flags, &cigar, &sequence, &quality_scores);
+        assert!(matches!(
+            result,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        let e = result.unwrap_err();
+        assert_eq!(
+            e.get_ref()
+                .and_then(|e| e.downcast_ref::<PositionOverflowError>())
+                .map(|e| e.op()),
+            Some(op)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_cigar_with_empty_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let flags = Flags::default();
+        let quality_scores = QualityScores::default();
+
+        let cigar = [Op::new(Kind::Match, 2)].into_iter().collect();
+        let sequence = Sequence::from(b"AC");
+        let actual = Features::try_from_cigar(flags, &cigar, &sequence, &quality_scores)?;
+        let expected = Features::from(vec![Feature::Bases(Position::MIN, vec![b'A', b'C'])]);
+        assert_eq!(actual, expected);
+
+        let cigar = [
+            Op::new(Kind::SoftClip, 1),
+            Op::new(Kind::Match, 1),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Insertion, 2),
+        ]
+        .into_iter()
+        .collect();
+        let sequence = Sequence::from(b"ACGTN");
+        let actual = Features::try_from_cigar(flags, &cigar, &sequence, &quality_scores)?;
+        let expected = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A']),
+            Feature::Bases(Position::try_from(2)?, vec![b'C']),
+            Feature::InsertBase(Position::try_from(3)?, b'G'),
+            Feature::Insertion(Position::try_from(4)?, vec![b'T', b'N']),
+        ]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_by_kind() -> Result<(), noodles_core::position::TryFromIntError> {
+        use crate::record::feature::substitution;
+
+        let features = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+            Feature::Substitution(Position::try_from(4)?, substitution::Value::Code(0)),
+            Feature::ReadBase(Position::try_from(5)?, b'N', 0),
+            Feature::Substitution(Position::try_from(6)?, substitution::Value::Code(1)),
+            Feature::Deletion(Position::try_from(7)?, 3),
+            Feature::InsertBase(Position::try_from(9)?, b'G'),
+            Feature::Insertion(Position::try_from(10)?, vec![b'T', b'T']),
+        ]);
+
+        let actual = features.count_by_kind();
+
+        let expected: HashMap<_, _> = [
+            (Code::SoftClip, 1),
+            (Code::Substitution, 2),
+            (Code::ReadBase, 1),
+            (Code::Deletion, 1),
+            (Code::InsertBase, 1),
+            (Code::Insertion, 1),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_span() -> Result<(), noodles_core::position::TryFromIntError> {
+        let features = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+            Feature::Substitution(
+                Position::try_from(4)?,
+                crate::record::feature::substitution::Value::Code(0),
+            ),
+            Feature::Deletion(Position::try_from(5)?, 3),
+            Feature::ReferenceSkip(Position::try_from(7)?, 100),
+            Feature::InsertBase(Position::try_from(9)?, b'G'),
+        ]);
+
+        // 12 read bases - 2 soft clipped - 1 inserted + 3 deleted + 100 skipped
+        assert_eq!(features.reference_span(12), Some(112));
+
+        let features = Features::from(vec![Feature::SoftClip(Position::MIN, vec![b'A'])]);
+        assert!(features.reference_span(0).is_none());
+
+        let features = Features::from(vec![Feature::Deletion(Position::MIN, usize::MAX)]);
+        assert!(features.reference_span(1).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_identity() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::feature::substitution;
+
+        let features = Features::from(vec![Feature::Substitution(
+            Position::try_from(2)?,
+            substitution::Value::Code(0),
+        )]);
+        assert_eq!(features.identity(4), Some(0.75));
+
+        let features = Features::default();
+        assert_eq!(features.identity(4), Some(1.0));
+
+        let features = Features::from(vec![Feature::SoftClip(Position::MIN, vec![b'A', b'C'])]);
+        assert!(features.identity(2).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_attach_scores() -> Result<(), Box<dyn std::error::Error>> {
+        let position = Position::try_from(2)?;
+
+        let mut features = Features::from(vec![
+            Feature::ReadBase(Position::MIN, b'N', 0),
+            Feature::Bases(position, vec![b'A', b'C']),
+            Feature::SoftClip(Position::try_from(4)?, vec![b'G']),
+        ]);
+
+        features.attach_scores(position, vec![45, 35])?;
+
+        let expected = Features::from(vec![
+            Feature::ReadBase(Position::MIN, b'N', 0),
+            Feature::Bases(position, vec![b'A', b'C']),
+            Feature::Scores(position, vec![45, 35]),
+            Feature::SoftClip(Position::try_from(4)?, vec![b'G']),
+        ]);
+        assert_eq!(features, expected);
+
+        features.attach_scores(position, vec![8, 13])?;
+        assert_eq!(features[2], Feature::Scores(position, vec![8, 13]));
+        assert_eq!(features.len(), 4);
+
+        assert!(matches!(
+            features.attach_scores(position, vec![45]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            features.attach_scores(Position::MIN, vec![45]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_into_cigar() -> Result<(), Box<dyn std::error::Error>> {
         use crate::record::feature::substitution;
@@ -526,4 +1380,85 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_into_cigar_with_invalid_clips() -> Result<(), Box<dyn std::error::Error>> {
+        let features = Features::from(vec![Feature::HardClip(Position::try_from(3)?, 1)]);
+        assert!(matches!(
+            features.try_into_cigar(4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let features = Features::from(vec![Feature::SoftClip(Position::try_from(2)?, vec![b'C'])]);
+        assert!(matches!(
+            features.try_into_cigar(4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cigar_to_features_to_cigar_round_trip() -> io::Result<()> {
+        fn t(flags: Flags, ops: &[(Kind, usize)], sequence: &[u8]) -> io::Result<()> {
+            let cigar: sam::alignment::record_buf::Cigar =
+                ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect();
+            let sequence = Sequence::from(sequence.to_vec());
+            let quality_scores = QualityScores::from(vec![45; sequence.len()]);
+
+            let features = Features::from_cigar(flags, &cigar, &sequence, &quality_scores);
+            let actual = features.try_into_cigar(sequence.len())?;
+            assert_eq!(actual, cigar);
+
+            Ok(())
+        }
+
+        for flags in [Flags::default(), Flags::QUALITY_SCORES_STORED_AS_ARRAY] {
+            t(flags, &[(Kind::Match, 4)], b"ACGT")?;
+            t(flags, &[(Kind::Match, 1)], b"A")?;
+            t(
+                flags,
+                &[(Kind::SoftClip, 1), (Kind::Match, 2), (Kind::SoftClip, 1)],
+                b"ACGT",
+            )?;
+            t(
+                flags,
+                &[
+                    (Kind::HardClip, 3),
+                    (Kind::SoftClip, 1),
+                    (Kind::Match, 3),
+                    (Kind::HardClip, 2),
+                ],
+                b"ACGT",
+            )?;
+            t(
+                flags,
+                &[
+                    (Kind::Match, 1),
+                    (Kind::Insertion, 1),
+                    (Kind::Match, 1),
+                    (Kind::Deletion, 2),
+                    (Kind::Match, 1),
+                ],
+                b"ACGT",
+            )?;
+            t(
+                flags,
+                &[
+                    (Kind::Match, 2),
+                    (Kind::Insertion, 2),
+                    (Kind::Skip, 100),
+                    (Kind::Match, 2),
+                ],
+                b"ACGTAC",
+            )?;
+            t(
+                flags,
+                &[(Kind::Match, 2), (Kind::Pad, 1), (Kind::Match, 2)],
+                b"ACGT",
+            )?;
+        }
+
+        Ok(())
+    }
 }
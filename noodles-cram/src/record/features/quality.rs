@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::record::Feature;
+
+pub(super) fn mean_matched_quality(features: &[Feature]) -> Option<f64> {
+    let unmatched_ranges: Vec<_> = features.iter().filter_map(unmatched_range).collect();
+
+    let mut scores = BTreeMap::new();
+
+    for feature in features {
+        match feature {
+            Feature::ReadBase(position, _, score) | Feature::QualityScore(position, score) => {
+                scores.insert(usize::from(*position), *score);
+            }
+            Feature::Scores(position, feature_scores) => {
+                let start = usize::from(*position);
+
+                for (i, &score) in feature_scores.iter().enumerate() {
+                    scores.insert(start + i, score);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (sum, n) = scores
+        .into_iter()
+        .filter(|(position, _)| !unmatched_ranges.iter().any(|r| r.contains(position)))
+        .fold((0u64, 0u64), |(sum, n), (_, score)| {
+            (sum + u64::from(score), n + 1)
+        });
+
+    if n == 0 {
+        None
+    } else {
+        Some(sum as f64 / n as f64)
+    }
+}
+
+fn unmatched_range(feature: &Feature) -> Option<Range<usize>> {
+    let (position, len) = match feature {
+        Feature::Insertion(position, bases) | Feature::SoftClip(position, bases) => {
+            (*position, bases.len())
+        }
+        Feature::InsertBase(position, _) => (*position, 1),
+        _ => return None,
+    };
+
+    let start = usize::from(position);
+    Some(start..start + len)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_mean_matched_quality() -> Result<(), noodles_core::position::TryFromIntError> {
+        let features = [
+            Feature::Bases(Position::MIN, vec![b'A', b'C']),
+            Feature::Scores(Position::MIN, vec![30, 40]),
+        ];
+        assert_eq!(mean_matched_quality(&features), Some(35.0));
+
+        let features = [
+            Feature::SoftClip(Position::MIN, vec![b'A']),
+            Feature::QualityScore(Position::MIN, 2),
+            Feature::ReadBase(Position::try_from(2)?, b'C', 30),
+            Feature::Insertion(Position::try_from(3)?, vec![b'G', b'T']),
+            Feature::Scores(Position::try_from(3)?, vec![5, 5, 20]),
+        ];
+        assert_eq!(mean_matched_quality(&features), Some(25.0));
+
+        assert!(mean_matched_quality(&[]).is_none());
+
+        let features = [
+            Feature::InsertBase(Position::MIN, b'A'),
+            Feature::QualityScore(Position::MIN, 8),
+        ];
+        assert!(mean_matched_quality(&features).is_none());
+
+        Ok(())
+    }
+}
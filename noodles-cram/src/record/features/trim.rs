@@ -0,0 +1,537 @@
+use noodles_core::Position;
+
+use crate::record::Feature;
+
+pub(super) fn trim_low_quality_ends(
+    features: &mut Vec<Feature>,
+    read_length: usize,
+    min_score: u8,
+) -> (usize, usize, usize) {
+    let (leading_start, leading_end) = leading_soft_clip_bounds(features);
+    let (trailing_start, trailing_end) = trailing_soft_clip_bounds(features, read_length);
+
+    let mut start = leading_end;
+    let mut leading_bases = Vec::new();
+    let mut trimmed_scores = Vec::new();
+
+    while start < trailing_start {
+        match take_low_quality_base(features, start, min_score) {
+            Some((base, score)) => {
+                leading_bases.push(base);
+                trimmed_scores.push((start, score));
+                start += 1;
+            }
+            None => break,
+        }
+    }
+
+    let mut end = trailing_start;
+    let mut trailing_bases = Vec::new();
+
+    while end > start {
+        match take_low_quality_base(features, end - 1, min_score) {
+            Some((base, score)) => {
+                trailing_bases.push(base);
+                trimmed_scores.push((end - 1, score));
+                end -= 1;
+            }
+            None => break,
+        }
+    }
+
+    trailing_bases.reverse();
+
+    let leading_count = leading_bases.len();
+    let trailing_count = trailing_bases.len();
+
+    let mut reference_offset = leading_count;
+
+    // Deletions and reference skips in or adjacent to the trimmed bases cannot border a soft
+    // clip, so they are removed. Those at the start also move the alignment start forward.
+    if leading_count > 0 {
+        let len = take_reference_gaps(features, leading_end, start);
+        reference_offset = reference_offset.saturating_add(len);
+        extend_soft_clip(features, leading_start, leading_end, leading_bases, false);
+    }
+
+    if trailing_count > 0 {
+        take_reference_gaps(features, end, trailing_start);
+        extend_soft_clip(features, trailing_start, trailing_end, trailing_bases, true);
+    }
+
+    for (position, score) in trimmed_scores {
+        features.push(Feature::QualityScore(to_position(position), score));
+    }
+
+    // This is a stable sort, so features at the same position keep their relative order.
+    features.sort_by_key(|feature| feature.position());
+
+    (leading_count, trailing_count, reference_offset)
+}
+
+pub(super) fn trim_front(features: &mut Vec<Feature>, n: usize) -> usize {
+    if n == 0 {
+        return 0;
+    }
+
+    let new_start = n.saturating_add(1);
+    let reference_len = trimmed_reference_len(features, new_start);
+
+    features.retain_mut(|feature| {
+        let start = usize::from(feature.position());
+
+        if start >= new_start {
+            set_position(feature, start - n);
+            return true;
+        }
+
+        let bases = match feature {
+            Feature::Bases(_, bases)
+            | Feature::Scores(_, bases)
+            | Feature::Insertion(_, bases)
+            | Feature::SoftClip(_, bases) => bases,
+            // A leading hard clip does not consume the read and stays before the first base.
+            Feature::HardClip(..) => return start == 1,
+            _ => return false,
+        };
+
+        let trimmed_len = new_start - start;
+
+        if bases.len() > trimmed_len {
+            bases.drain(..trimmed_len);
+            set_position(feature, 1);
+            true
+        } else {
+            false
+        }
+    });
+
+    reference_len
+}
+
+/// Returns the number of reference bases consumed by the read bases before `end`.
+///
+/// This is the number of aligned bases, i.e., those not in a soft clip or insertion, and the
+/// lengths of deletions and reference skips before `end`.
+fn trimmed_reference_len(features: &[Feature], end: usize) -> usize {
+    fn overlap(start: usize, len: usize, end: usize) -> usize {
+        end.saturating_sub(start).min(len)
+    }
+
+    let mut unaligned_len = 0;
+    let mut gap_len = 0;
+
+    for feature in features {
+        let start = usize::from(feature.position());
+
+        if start >= end {
+            continue;
+        }
+
+        match feature {
+            Feature::SoftClip(_, bases) | Feature::Insertion(_, bases) => {
+                unaligned_len += overlap(start, bases.len(), end);
+            }
+            Feature::InsertBase(..) => unaligned_len += 1,
+            Feature::Deletion(_, len) | Feature::ReferenceSkip(_, len) => {
+                gap_len = usize::saturating_add(gap_len, *len);
+            }
+            _ => {}
+        }
+    }
+
+    (end - 1)
+        .saturating_sub(unaligned_len)
+        .saturating_add(gap_len)
+}
+
+fn set_position(feature: &mut Feature, n: usize) {
+    let position = to_position(n);
+
+    match feature {
+        Feature::Bases(pos, _)
+        | Feature::Scores(pos, _)
+        | Feature::ReadBase(pos, _, _)
+        | Feature::Substitution(pos, _)
+        | Feature::Insertion(pos, _)
+        | Feature::Deletion(pos, _)
+        | Feature::InsertBase(pos, _)
+        | Feature::QualityScore(pos, _)
+        | Feature::ReferenceSkip(pos, _)
+        | Feature::SoftClip(pos, _)
+        | Feature::Padding(pos, _)
+        | Feature::HardClip(pos, _) => *pos = position,
+    }
+}
+
+/// Returns the 1-based [start, end) read positions of the leading soft clip.
+fn leading_soft_clip_bounds(features: &[Feature]) -> (usize, usize) {
+    const START: usize = 1;
+
+    let len = features
+        .iter()
+        .find_map(|feature| match feature {
+            Feature::SoftClip(position, bases) if usize::from(*position) == START => {
+                Some(bases.len())
+            }
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    (START, START + len)
+}
+
+/// Returns the 1-based [start, end) read positions of the trailing soft clip.
+fn trailing_soft_clip_bounds(features: &[Feature], read_length: usize) -> (usize, usize) {
+    let end = read_length + 1;
+
+    let start = features
+        .iter()
+        .find_map(|feature| match feature {
+            Feature::SoftClip(position, bases)
+                if usize::from(*position) > 1 && usize::from(*position) + bases.len() == end =>
+            {
+                Some(usize::from(*position))
+            }
+            _ => None,
+        })
+        .unwrap_or(end);
+
+    (start, end)
+}
+
+/// Removes the base at the given read position if it has a quality score less than `min_score`.
+///
+/// The base is either a read base or the first or last base of a run of bases with scores. This
+/// returns the removed base and its quality score.
+fn take_low_quality_base(
+    features: &mut Vec<Feature>,
+    position: usize,
+    min_score: u8,
+) -> Option<(u8, u8)> {
+    let read_base_index = features.iter().position(|feature| match feature {
+        Feature::ReadBase(p, _, _) => usize::from(*p) == position,
+        _ => false,
+    });
+
+    if let Some(i) = read_base_index {
+        return match features[i] {
+            Feature::ReadBase(_, base, score) if score < min_score => {
+                features.remove(i);
+                Some((base, score))
+            }
+            _ => None,
+        };
+    }
+
+    let i = features.iter().position(|feature| match feature {
+        Feature::Bases(p, bases) => {
+            let start = usize::from(*p);
+            (start..start + bases.len()).contains(&position)
+        }
+        _ => false,
+    })?;
+
+    let (run_start, run_len) = match &features[i] {
+        Feature::Bases(p, bases) => (usize::from(*p), bases.len()),
+        _ => unreachable!(),
+    };
+
+    let j = features.iter().position(|feature| match feature {
+        Feature::Scores(p, scores) => usize::from(*p) == run_start && scores.len() == run_len,
+        _ => false,
+    })?;
+
+    let is_front = position == run_start;
+
+    if !is_front && position != run_start + run_len - 1 {
+        return None;
+    }
+
+    let score = match &features[j] {
+        Feature::Scores(_, scores) => scores[position - run_start],
+        _ => unreachable!(),
+    };
+
+    if score >= min_score {
+        return None;
+    }
+
+    let base = take_run_end(&mut features[i], is_front);
+    take_run_end(&mut features[j], is_front);
+
+    if run_len == 1 {
+        features.remove(i.max(j));
+        features.remove(i.min(j));
+    }
+
+    Some((base, score))
+}
+
+/// Removes the first or last value of a run of bases or scores.
+///
+/// When the first value is removed, the run starts at the next read position.
+fn take_run_end(feature: &mut Feature, is_front: bool) -> u8 {
+    let (position, values) = match feature {
+        Feature::Bases(position, values) | Feature::Scores(position, values) => (position, values),
+        _ => unreachable!(),
+    };
+
+    if is_front {
+        let value = values.remove(0);
+
+        if !values.is_empty() {
+            *position = to_position(usize::from(*position) + 1);
+        }
+
+        value
+    } else {
+        values.pop().expect("empty run")
+    }
+}
+
+/// Removes deletions and reference skips in the 1-based [start, end] read positions.
+///
+/// This returns the total number of reference bases they consumed.
+fn take_reference_gaps(features: &mut Vec<Feature>, start: usize, end: usize) -> usize {
+    let mut len = 0;
+
+    features.retain(|feature| match feature {
+        Feature::Deletion(p, n) | Feature::ReferenceSkip(p, n)
+            if (start..=end).contains(&usize::from(*p)) =>
+        {
+            len = usize::saturating_add(len, *n);
+            false
+        }
+        _ => true,
+    });
+
+    len
+}
+
+/// Removes the soft clip in the 1-based [start, end) read positions.
+///
+/// This returns the index of the removed soft clip and its bases.
+fn take_soft_clip_bases(
+    features: &mut Vec<Feature>,
+    start: usize,
+    end: usize,
+) -> Option<(usize, Vec<u8>)> {
+    let i = features.iter().position(|feature| match feature {
+        Feature::SoftClip(p, bases) => usize::from(*p) == start && start + bases.len() == end,
+        _ => false,
+    })?;
+
+    match features.remove(i) {
+        Feature::SoftClip(_, bases) => Some((i, bases)),
+        _ => unreachable!(),
+    }
+}
+
+fn extend_soft_clip(
+    features: &mut Vec<Feature>,
+    start: usize,
+    end: usize,
+    bases: Vec<u8>,
+    is_trailing: bool,
+) {
+    let (index, existing_bases) = match take_soft_clip_bases(features, start, end) {
+        Some((i, bases)) => (Some(i), bases),
+        None => (None, Vec::new()),
+    };
+
+    let (position, bases) = if is_trailing {
+        let position = start - bases.len();
+        let mut merged = bases;
+        merged.extend(existing_bases);
+        (position, merged)
+    } else {
+        let mut merged = existing_bases;
+        merged.extend(bases);
+        (start, merged)
+    };
+
+    // The soft clip replaces the existing one, if any, to keep its order relative to other
+    // features at the same position, e.g., a leading hard clip and the soft clip scores.
+    let feature = Feature::SoftClip(to_position(position), bases);
+
+    match index {
+        Some(i) => features.insert(i, feature),
+        None => features.push(feature),
+    }
+}
+
+fn to_position(n: usize) -> Position {
+    Position::new(n).expect("invalid position")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_low_quality_ends() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut features = vec![Feature::ReadBase(Position::MIN, b'A', 2)];
+        assert_eq!(trim_low_quality_ends(&mut features, 4, 10), (1, 0, 1));
+        assert_eq!(
+            features,
+            [
+                Feature::SoftClip(Position::MIN, vec![b'A']),
+                Feature::QualityScore(Position::MIN, 2),
+            ]
+        );
+
+        let mut features = vec![
+            Feature::HardClip(Position::MIN, 5),
+            Feature::SoftClip(Position::MIN, vec![b'N']),
+            Feature::QualityScore(Position::MIN, 0),
+            Feature::ReadBase(Position::try_from(2)?, b'A', 2),
+            Feature::ReadBase(Position::try_from(3)?, b'C', 3),
+            Feature::ReadBase(Position::try_from(4)?, b'G', 30),
+            Feature::ReadBase(Position::try_from(7)?, b'T', 40),
+            Feature::ReadBase(Position::try_from(8)?, b'A', 4),
+        ];
+        assert_eq!(trim_low_quality_ends(&mut features, 8, 10), (2, 1, 2));
+        assert_eq!(
+            features,
+            [
+                Feature::HardClip(Position::MIN, 5),
+                Feature::SoftClip(Position::MIN, vec![b'N', b'A', b'C']),
+                Feature::QualityScore(Position::MIN, 0),
+                Feature::QualityScore(Position::try_from(2)?, 2),
+                Feature::QualityScore(Position::try_from(3)?, 3),
+                Feature::ReadBase(Position::try_from(4)?, b'G', 30),
+                Feature::ReadBase(Position::try_from(7)?, b'T', 40),
+                Feature::SoftClip(Position::try_from(8)?, vec![b'A']),
+                Feature::QualityScore(Position::try_from(8)?, 4),
+            ]
+        );
+
+        let mut features = vec![
+            Feature::ReadBase(Position::try_from(2)?, b'A', 2),
+            Feature::SoftClip(Position::try_from(4)?, vec![b'C']),
+        ];
+        assert_eq!(trim_low_quality_ends(&mut features, 4, 10), (0, 0, 0));
+
+        let mut features = vec![
+            Feature::ReadBase(Position::try_from(1)?, b'A', 2),
+            Feature::ReadBase(Position::try_from(2)?, b'C', 2),
+        ];
+        assert_eq!(trim_low_quality_ends(&mut features, 2, 10), (2, 0, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_low_quality_ends_with_bases() -> Result<(), noodles_core::position::TryFromIntError>
+    {
+        // 4M
+        let mut features = vec![
+            Feature::Bases(Position::MIN, b"ACGT".to_vec()),
+            Feature::Scores(Position::MIN, vec![2, 30, 30, 3]),
+        ];
+        assert_eq!(trim_low_quality_ends(&mut features, 4, 10), (1, 1, 1));
+        assert_eq!(
+            features,
+            [
+                Feature::SoftClip(Position::MIN, vec![b'A']),
+                Feature::QualityScore(Position::MIN, 2),
+                Feature::Bases(Position::try_from(2)?, b"CG".to_vec()),
+                Feature::Scores(Position::try_from(2)?, vec![30, 30]),
+                Feature::SoftClip(Position::try_from(4)?, vec![b'T']),
+                Feature::QualityScore(Position::try_from(4)?, 3),
+            ]
+        );
+
+        // 1S2M3D2M
+        let mut features = vec![
+            Feature::SoftClip(Position::MIN, vec![b'N']),
+            Feature::QualityScore(Position::MIN, 0),
+            Feature::Bases(Position::try_from(2)?, b"AC".to_vec()),
+            Feature::Scores(Position::try_from(2)?, vec![2, 3]),
+            Feature::Deletion(Position::try_from(4)?, 3),
+            Feature::Bases(Position::try_from(4)?, b"GT".to_vec()),
+            Feature::Scores(Position::try_from(4)?, vec![30, 40]),
+        ];
+        assert_eq!(trim_low_quality_ends(&mut features, 5, 10), (2, 0, 5));
+        assert_eq!(
+            features,
+            [
+                Feature::SoftClip(Position::MIN, vec![b'N', b'A', b'C']),
+                Feature::QualityScore(Position::MIN, 0),
+                Feature::QualityScore(Position::try_from(2)?, 2),
+                Feature::QualityScore(Position::try_from(3)?, 3),
+                Feature::Bases(Position::try_from(4)?, b"GT".to_vec()),
+                Feature::Scores(Position::try_from(4)?, vec![30, 40]),
+            ]
+        );
+
+        // Runs without scores are not trimmed.
+        let mut features = vec![Feature::Bases(Position::MIN, b"AC".to_vec())];
+        assert_eq!(trim_low_quality_ends(&mut features, 2, 10), (0, 0, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_front() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut features = vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C', b'G']),
+            Feature::ReadBase(Position::try_from(2)?, b'C', 30),
+            Feature::Substitution(
+                Position::try_from(5)?,
+                crate::record::feature::substitution::Value::Code(0),
+            ),
+            Feature::Deletion(Position::try_from(6)?, 2),
+        ];
+
+        assert_eq!(trim_front(&mut features, 2), 0);
+
+        let expected = [
+            Feature::SoftClip(Position::try_from(1)?, vec![b'G']),
+            Feature::Substitution(
+                Position::try_from(3)?,
+                crate::record::feature::substitution::Value::Code(0),
+            ),
+            Feature::Deletion(Position::try_from(4)?, 2),
+        ];
+
+        assert_eq!(features, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_front_with_hard_clip_and_deletion(
+    ) -> Result<(), noodles_core::position::TryFromIntError> {
+        // 5H1M3D3M1N1M
+        let mut features = vec![
+            Feature::HardClip(Position::MIN, 5),
+            Feature::ReadBase(Position::MIN, b'A', 30),
+            Feature::Deletion(Position::try_from(2)?, 3),
+            Feature::ReadBase(Position::try_from(3)?, b'C', 30),
+            Feature::ReferenceSkip(Position::try_from(5)?, 8),
+        ];
+
+        assert_eq!(trim_front(&mut features, 2), 5);
+
+        let expected = [
+            Feature::HardClip(Position::MIN, 5),
+            Feature::ReadBase(Position::MIN, b'C', 30),
+            Feature::ReferenceSkip(Position::try_from(3)?, 8),
+        ];
+
+        assert_eq!(features, expected);
+
+        // 2S1I2M
+        let mut features = vec![
+            Feature::SoftClip(Position::MIN, vec![b'A', b'C']),
+            Feature::InsertBase(Position::try_from(3)?, b'G'),
+        ];
+
+        assert_eq!(trim_front(&mut features, 4), 1);
+        assert!(features.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use noodles_core::Position;
+
+use crate::record::{feature::substitution, Feature};
+
+const GAP: char = '-';
+const MATCH: char = '=';
+const SKIP: char = '~';
+const PAD: char = '*';
+const CLIP: char = ' ';
+const UNKNOWN_BASE: char = 'X';
+
+pub(super) fn visualize(
+    features: &[Feature],
+    alignment_start: Position,
+    read_length: usize,
+) -> String {
+    let mut reference_line = String::new();
+    let mut read_line = String::new();
+
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = usize::from(Position::MIN);
+
+    let push_matches = |reference_line: &mut String,
+                        read_line: &mut String,
+                        reference_position: &mut usize,
+                        read_position: &mut usize,
+                        end: usize| {
+        while *read_position < end {
+            reference_line.push(reference_digit(*reference_position));
+            read_line.push(MATCH);
+            *reference_position += 1;
+            *read_position += 1;
+        }
+    };
+
+    for feature in features {
+        push_matches(
+            &mut reference_line,
+            &mut read_line,
+            &mut reference_position,
+            &mut read_position,
+            usize::from(feature.position()),
+        );
+
+        match feature {
+            Feature::Bases(_, bases) => {
+                for &base in bases {
+                    reference_line.push(reference_digit(reference_position));
+                    read_line.push(char::from(base));
+                    reference_position += 1;
+                    read_position += 1;
+                }
+            }
+            Feature::ReadBase(_, base, _) => {
+                reference_line.push(reference_digit(reference_position));
+                read_line.push(char::from(*base));
+                reference_position += 1;
+                read_position += 1;
+            }
+            Feature::Substitution(_, value) => {
+                let base = match value {
+                    substitution::Value::Bases(_, read_base) => char::from(u8::from(*read_base)),
+                    substitution::Value::Code(_) => UNKNOWN_BASE,
+                };
+
+                reference_line.push(reference_digit(reference_position));
+                read_line.push(base);
+                reference_position += 1;
+                read_position += 1;
+            }
+            Feature::Insertion(_, bases) => {
+                for &base in bases {
+                    reference_line.push(GAP);
+                    read_line.push(char::from(base));
+                    read_position += 1;
+                }
+            }
+            Feature::InsertBase(_, base) => {
+                reference_line.push(GAP);
+                read_line.push(char::from(*base));
+                read_position += 1;
+            }
+            Feature::Deletion(_, len) => {
+                for _ in 0..*len {
+                    reference_line.push(reference_digit(reference_position));
+                    read_line.push(GAP);
+                    reference_position += 1;
+                }
+            }
+            Feature::ReferenceSkip(_, len) => {
+                reference_line.push(SKIP);
+                read_line.push(SKIP);
+                reference_position += len;
+            }
+            Feature::SoftClip(_, bases) => {
+                for &base in bases {
+                    reference_line.push(CLIP);
+                    read_line.push(char::from(base.to_ascii_lowercase()));
+                    read_position += 1;
+                }
+            }
+            Feature::Padding(_, len) => {
+                for _ in 0..*len {
+                    reference_line.push(PAD);
+                    read_line.push(PAD);
+                }
+            }
+            Feature::Scores(..) | Feature::QualityScore(..) | Feature::HardClip(..) => {}
+        }
+    }
+
+    push_matches(
+        &mut reference_line,
+        &mut read_line,
+        &mut reference_position,
+        &mut read_position,
+        read_length + 1,
+    );
+
+    reference_line.push('\n');
+    reference_line.push_str(&read_line);
+
+    reference_line
+}
+
+fn reference_digit(position: usize) -> char {
+    let n = (position % 10) as u32;
+    char::from_digit(n, 10).expect("invalid digit")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visualize() -> Result<(), noodles_core::position::TryFromIntError> {
+        let alignment_start = Position::try_from(8)?;
+
+        let features = [Feature::Deletion(Position::MIN, 1)];
+        assert_eq!(visualize(&features, alignment_start, 2), "890\n-==");
+
+        let features = [
+            Feature::SoftClip(Position::MIN, vec![b'A']),
+            Feature::InsertBase(Position::try_from(3)?, b'C'),
+            Feature::ReadBase(Position::try_from(4)?, b'G', 0),
+        ];
+        assert_eq!(visualize(&features, alignment_start, 5), " 8-90\na=CG=");
+
+        let features = [
+            Feature::ReferenceSkip(Position::try_from(2)?, 100),
+            Feature::Padding(Position::try_from(2)?, 1),
+        ];
+        assert_eq!(visualize(&features, alignment_start, 2), "8~*9\n=~*=");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use std::slice;
+
+use noodles_core::Position;
+
+use super::WithPositions;
+use crate::record::Feature;
+
+/// An iterator over features with their reference positions.
+///
+/// The reference position advances on matches, deletions, and reference skips and is held on
+/// insertions, soft clips, padding, and hard clips.
+pub struct WithReferencePositions<'a> {
+    iter: WithPositions<'a, slice::Iter<'a, Feature>>,
+}
+
+impl<'a> WithReferencePositions<'a> {
+    pub(super) fn new(features: &'a [Feature], alignment_start: Position) -> Self {
+        Self {
+            iter: WithPositions::new(features.iter(), alignment_start),
+        }
+    }
+}
+
+impl<'a> Iterator for WithReferencePositions<'a> {
+    type Item = (Position, &'a Feature);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|((reference_position, _), feature)| (reference_position, feature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let features = [
+            Feature::ReadBase(Position::try_from(2)?, b'A', 0),
+            Feature::Deletion(Position::try_from(4)?, 3),
+            Feature::Insertion(Position::try_from(6)?, vec![b'C', b'G']),
+            Feature::SoftClip(Position::try_from(10)?, vec![b'T']),
+        ];
+
+        let mut iter = WithReferencePositions::new(&features, Position::try_from(100)?);
+
+        assert_eq!(iter.next(), Some((Position::try_from(101)?, &features[0])));
+        assert_eq!(iter.next(), Some((Position::try_from(103)?, &features[1])));
+        assert_eq!(iter.next(), Some((Position::try_from(108)?, &features[2])));
+        assert_eq!(iter.next(), Some((Position::try_from(110)?, &features[3])));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
@@ -36,6 +36,14 @@ where
         }
     }
 
+    /// Returns the current virtual position of the underlying reader.
+    ///
+    /// This can be used to resume reading at the current position, e.g., by seeking a new
+    /// reader to it. Note that chunk boundaries are not tracked in the returned position.
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.reader.virtual_position()
+    }
+
     /// Creates an iterator that parses indexed records.
     pub fn indexed_records(self, header: &Header) -> IndexedRecords<Self> {
         IndexedRecords::new(self, header)
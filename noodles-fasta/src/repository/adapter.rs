@@ -6,4 +6,75 @@ use crate::Record;
 pub trait Adapter: Send + Sync {
     /// Returns the record with the given name.
     fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>>;
+
+    /// Returns the records with the given names.
+    ///
+    /// The results are in the same order as `names`. By default, this calls [`Self::get`] for
+    /// each name. Adapters that can batch reads should override this.
+    fn get_many(&mut self, names: &[&[u8]]) -> Vec<Option<io::Result<Record>>> {
+        names.iter().map(|name| self.get(name)).collect()
+    }
+
+    /// Returns the length of the sequence of the record with the given name.
+    ///
+    /// By default, this reads the record using [`Self::get`]. Adapters that can get the length
+    /// without loading the sequence, e.g., from an index, should override this.
+    fn sequence_len(&mut self, name: &[u8]) -> Option<io::Result<usize>> {
+        self.get(name)
+            .map(|result| result.map(|record| record.sequence().len()))
+    }
+
+    /// Returns the names of the records the adapter can serve.
+    ///
+    /// By default, this returns an empty list for adapters that cannot enumerate their records.
+    fn names(&mut self) -> io::Result<Vec<Vec<u8>>> {
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    struct T;
+
+    impl Adapter for T {
+        fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+            match name {
+                b"sq0" => Some(Ok(Record::new(
+                    Definition::new("sq0", None),
+                    Sequence::from(b"ACGT".to_vec()),
+                ))),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_many() -> io::Result<()> {
+        let mut adapter = T;
+
+        let records = adapter.get_many(&[b"sq0", b"sq1", b"sq0"]);
+        let names: Vec<_> = records
+            .into_iter()
+            .map(|result| {
+                result
+                    .transpose()
+                    .map(|record| record.map(|r| r.name().to_vec()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some(b"sq0".to_vec()), None, Some(b"sq0".to_vec())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequence_len() -> io::Result<()> {
+        let mut adapter = T;
+        assert_eq!(adapter.sequence_len(b"sq0").transpose()?, Some(4));
+        assert!(adapter.sequence_len(b"sq1").is_none());
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 
 mod empty;
 mod indexed_reader;
+mod map;
 mod records;
 
-pub use self::{empty::Empty, indexed_reader::IndexedReader};
+pub use self::{empty::Empty, indexed_reader::IndexedReader, map::Map};
@@ -37,4 +37,56 @@ where
         let region = Region::new(name, ..);
         Some(self.reader.query(&region))
     }
+
+    fn sequence_len(&mut self, name: &[u8]) -> Option<io::Result<usize>> {
+        self.reader
+            .index()
+            .iter()
+            .find(|record| record.name() == name)
+            .map(|record| {
+                usize::try_from(record.length())
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+    }
+
+    fn names(&mut self) -> io::Result<Vec<Vec<u8>>> {
+        Ok(self
+            .reader
+            .index()
+            .iter()
+            .map(|record| record.name().to_vec())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fai;
+
+    #[test]
+    fn test_sequence_len() -> io::Result<()> {
+        let index = vec![fai::Record::new("sq0", 4, 5, 4, 5)];
+        let reader = crate::IndexedReader::new(io::empty(), index);
+        let mut adapter = IndexedReader::new(reader);
+
+        assert_eq!(adapter.sequence_len(b"sq0").transpose()?, Some(4));
+        assert!(adapter.sequence_len(b"sq1").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_names() -> io::Result<()> {
+        let index = vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 4, 15, 4, 5),
+        ];
+        let reader = crate::IndexedReader::new(io::empty(), index);
+        let mut adapter = IndexedReader::new(reader);
+
+        assert_eq!(adapter.names()?, [b"sq0".to_vec(), b"sq1".to_vec()]);
+
+        Ok(())
+    }
 }
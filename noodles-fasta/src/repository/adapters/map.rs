@@ -0,0 +1,95 @@
+use std::{collections::HashMap, io};
+
+use crate::{repository::Adapter, Record};
+
+/// A map adapter.
+///
+/// This adapter is backed by an in-memory map of record names to records.
+#[derive(Debug, Default)]
+pub struct Map(HashMap<Vec<u8>, Record>);
+
+impl Map {
+    /// Creates an empty map adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::Map;
+    /// let adapter = Map::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<HashMap<Vec<u8>, Record>> for Map {
+    fn from(records: HashMap<Vec<u8>, Record>) -> Self {
+        Self(records)
+    }
+}
+
+impl FromIterator<Record> for Map {
+    fn from_iter<T: IntoIterator<Item = Record>>(iter: T) -> Self {
+        let records = iter
+            .into_iter()
+            .map(|record| (record.name().to_vec(), record))
+            .collect();
+
+        Self(records)
+    }
+}
+
+impl Adapter for Map {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+        self.0.get(name).cloned().map(Ok)
+    }
+
+    fn names(&mut self) -> io::Result<Vec<Vec<u8>>> {
+        let mut names: Vec<_> = self.0.keys().cloned().collect();
+        names.sort();
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    fn build_adapter() -> Map {
+        [("sq0", b"ACGT"), ("sq1", b"NNNN")]
+            .into_iter()
+            .map(|(name, sequence)| {
+                Record::new(
+                    Definition::new(name, None),
+                    Sequence::from(sequence.to_vec()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let mut adapter = build_adapter();
+
+        let record = adapter.get(b"sq1").transpose()?;
+        assert_eq!(
+            record,
+            Some(Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"NNNN".to_vec()),
+            ))
+        );
+
+        assert!(adapter.get(b"sq2").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_names() -> io::Result<()> {
+        let mut adapter = build_adapter();
+        assert_eq!(adapter.names()?, [b"sq0".to_vec(), b"sq1".to_vec()]);
+        Ok(())
+    }
+}
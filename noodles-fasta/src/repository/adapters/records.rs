@@ -9,4 +9,29 @@ impl Adapter for Vec<Record> {
             .cloned()
             .map(Ok)
     }
+
+    fn names(&mut self) -> io::Result<Vec<Vec<u8>>> {
+        Ok(self.iter().map(|record| record.name().to_vec()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    #[test]
+    fn test_names() -> io::Result<()> {
+        let mut adapter: Vec<_> = ["sq0", "sq1", "sq2"]
+            .into_iter()
+            .map(|name| Record::new(Definition::new(name, None), Sequence::default()))
+            .collect();
+
+        assert_eq!(
+            adapter.names()?,
+            [b"sq0".to_vec(), b"sq1".to_vec(), b"sq2".to_vec()]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,113 @@
+//! GFF header.
+
+use super::{
+    directive::{GffVersion, SequenceRegion},
+    Directive,
+};
+
+/// A GFF header.
+///
+/// This is the leading block of directives and comments before the first record.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Header {
+    gff_version: Option<GffVersion>,
+    sequence_regions: Vec<SequenceRegion>,
+    other_directives: Vec<Directive>,
+    comments: Vec<String>,
+}
+
+impl Header {
+    /// Returns the GFF version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let header = gff::Header::default();
+    /// assert!(header.gff_version().is_none());
+    /// ```
+    pub fn gff_version(&self) -> Option<&GffVersion> {
+        self.gff_version.as_ref()
+    }
+
+    /// Returns the sequence regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let header = gff::Header::default();
+    /// assert!(header.sequence_regions().is_empty());
+    /// ```
+    pub fn sequence_regions(&self) -> &[SequenceRegion] {
+        &self.sequence_regions
+    }
+
+    /// Returns the directives that are not the GFF version or a sequence region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let header = gff::Header::default();
+    /// assert!(header.other_directives().is_empty());
+    /// ```
+    pub fn other_directives(&self) -> &[Directive] {
+        &self.other_directives
+    }
+
+    /// Returns the comments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let header = gff::Header::default();
+    /// assert!(header.comments().is_empty());
+    /// ```
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    pub(crate) fn add_directive(&mut self, directive: Directive) {
+        match directive {
+            Directive::GffVersion(gff_version) => self.gff_version = Some(gff_version),
+            Directive::SequenceRegion(sequence_region) => {
+                self.sequence_regions.push(sequence_region)
+            }
+            _ => self.other_directives.push(directive),
+        }
+    }
+
+    pub(crate) fn add_comment(&mut self, comment: String) {
+        self.comments.push(comment);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_directive() {
+        let mut header = Header::default();
+
+        header.add_directive(Directive::GffVersion(GffVersion::default()));
+        header.add_directive(Directive::SequenceRegion(SequenceRegion::new(
+            String::from("sq0"),
+            1,
+            8,
+        )));
+        header.add_directive(Directive::Species(String::from("ndls")));
+
+        assert_eq!(header.gff_version(), Some(&GffVersion::default()));
+        assert_eq!(
+            header.sequence_regions(),
+            [SequenceRegion::new(String::from("sq0"), 1, 8)]
+        );
+        assert_eq!(
+            header.other_directives(),
+            [Directive::Species(String::from("ndls"))]
+        );
+    }
+}
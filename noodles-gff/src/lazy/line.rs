@@ -1,4 +1,5 @@
 use super::Record;
+use crate::{directive, Directive};
 
 /// An immutable, lazily-evalulated GFF line.
 pub enum Line {
@@ -10,6 +11,31 @@ pub enum Line {
     Record(Record),
 }
 
+impl Line {
+    /// Parses the line as a directive.
+    ///
+    /// This returns `None` if the line is not a directive. Unrecognized directives are parsed as
+    /// [`Directive::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{lazy, Directive};
+    ///
+    /// let line = lazy::Line::Directive(String::from("##FASTA"));
+    /// assert_eq!(line.as_directive(), Some(Ok(Directive::StartOfFasta)));
+    ///
+    /// let line = lazy::Line::Comment(String::from("#noodles"));
+    /// assert!(line.as_directive().is_none());
+    /// ```
+    pub fn as_directive(&self) -> Option<Result<Directive, directive::ParseError>> {
+        match self {
+            Self::Directive(s) => Some(s.parse()),
+            _ => None,
+        }
+    }
+}
+
 impl Default for Line {
     fn default() -> Self {
         Self::Comment(String::new())
@@ -25,3 +51,40 @@ impl From<Line> for String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_directive() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::directive::{GffVersion, SequenceRegion};
+
+        let line = Line::Directive(String::from("##gff-version 3"));
+        assert_eq!(
+            line.as_directive(),
+            Some(Ok(Directive::GffVersion(GffVersion::default())))
+        );
+
+        let line = Line::Directive(String::from("##sequence-region sq0 8 13"));
+        assert_eq!(
+            line.as_directive(),
+            Some(Ok(Directive::SequenceRegion(SequenceRegion::new(
+                String::from("sq0"),
+                8,
+                13
+            ))))
+        );
+
+        let line = Line::Directive(String::from("##foo"));
+        assert_eq!(
+            line.as_directive(),
+            Some(Ok(Directive::Other("foo".parse()?, None)))
+        );
+
+        let line = Line::Comment(String::from("#noodles"));
+        assert!(line.as_directive().is_none());
+
+        Ok(())
+    }
+}
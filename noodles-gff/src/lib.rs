@@ -32,10 +32,14 @@
 //! ```
 
 pub mod directive;
+pub mod header;
 pub mod lazy;
 pub mod line;
 pub mod reader;
 pub mod record;
 mod writer;
 
-pub use self::{directive::Directive, line::Line, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    directive::Directive, header::Header, line::Line, reader::Reader, record::Record,
+    writer::Writer,
+};
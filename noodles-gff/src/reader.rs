@@ -1,19 +1,32 @@
 //! GFF reader and iterators.
 
+mod dedup_records;
+mod fasta_records;
+mod line_limited;
 mod lines;
+mod query_planner;
 mod records;
+mod records_and_directives;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{
+    dedup_records::DedupRecords, fasta_records::FastaRecords, lines::Lines,
+    query_planner::QueryPlanner, records::Records, records_and_directives::RecordsAndDirectives,
+};
 
 use std::{
-    io::{self, BufRead, Read, Seek},
-    mem, str,
+    io::{self, BufRead, BufReader, Read, Seek},
+    iter, mem, str,
 };
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::{self as csi, BinningIndex};
+use noodles_csi::{
+    self as csi,
+    binning_index::index::{reference_sequence::bin::Chunk, Header},
+    BinningIndex,
+};
 
+use self::line_limited::LineLimited;
 use super::{lazy, Record};
 
 const LINE_FEED: char = '\n';
@@ -22,6 +35,7 @@ const CARRIAGE_RETURN: char = '\r';
 /// A GFF reader.
 pub struct Reader<R> {
     inner: R,
+    max_line_length: Option<usize>,
 }
 
 impl<R> Reader<R>
@@ -38,7 +52,37 @@ where
     /// let mut reader = gff::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            max_line_length: None,
+        }
+    }
+
+    /// Sets the maximum line length.
+    ///
+    /// The length excludes the line terminator. When set, reading a line that is longer than the
+    /// maximum returns an error of kind [`io::ErrorKind::InvalidData`], rather than buffering the
+    /// entire line. This applies to every method that reads lines, including lazy records,
+    /// queries, and the bundled FASTA section. By default, line lengths are unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3\n";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// reader.set_max_line_length(Some(8));
+    ///
+    /// let mut buf = String::new();
+    /// assert!(matches!(
+    ///     reader.read_line(&mut buf),
+    ///     Err(e) if e.kind() == io::ErrorKind::InvalidData
+    /// ));
+    /// ```
+    pub fn set_max_line_length(&mut self, max_line_length: Option<usize>) {
+        self.max_line_length = max_line_length;
     }
 
     /// Returns a reference to the underlying reader.
@@ -107,7 +151,7 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
-        read_line(&mut self.inner, buf)
+        read_line(&mut self.limited(), buf)
     }
 
     /// Returns an iterator over lines starting from the current stream position.
@@ -142,17 +186,70 @@ where
         Lines::new(self)
     }
 
+    /// Reads the GFF header.
+    ///
+    /// This reads the leading block of directives and comments, stopping at the first record,
+    /// which is left unread. If the `FASTA` directive is reached, it is read into the header, and
+    /// the stream is positioned at the start of the bundled FASTA section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// ###sequence-region sq0 1 21
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    ///
+    /// let header = reader.read_header()?;
+    /// assert!(header.gff_version().is_some());
+    /// assert_eq!(header.sequence_regions().len(), 1);
+    ///
+    /// assert!(reader.records().next().transpose()?.is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<crate::Header> {
+        use crate::{Directive, Line};
+
+        let mut header = crate::Header::default();
+        let mut buf = String::new();
+
+        while let Some(LineType::Comment) = peek_line_type(&mut self.inner)? {
+            buf.clear();
+            self.read_line(&mut buf)?;
+
+            match buf
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            {
+                Line::Directive(Directive::StartOfFasta) => {
+                    header.add_directive(Directive::StartOfFasta);
+                    break;
+                }
+                Line::Directive(directive) => header.add_directive(directive),
+                Line::Comment(comment) => header.add_comment(comment),
+                Line::Record(_) => unreachable!(),
+            }
+        }
+
+        Ok(header)
+    }
+
     /// Reads a single line without eagerly decoding it.
     pub fn read_lazy_line(&mut self, line: &mut lazy::Line) -> io::Result<usize> {
         const DEFAULT_LINE: lazy::Line = lazy::Line::Comment(String::new());
         const DIRECTIVE_PREFIX: &str = "##";
 
         let prev_line = mem::replace(line, DEFAULT_LINE);
-        let mut buf = prev_line.into();
+        let mut buf: String = prev_line.into();
 
         match peek_line_type(&mut self.inner)? {
             Some(LineType::Comment) => {
-                let n = read_line(&mut self.inner, &mut buf)?;
+                buf.clear();
+                let n = self.read_line(&mut buf)?;
 
                 *line = if buf.starts_with(DIRECTIVE_PREFIX) {
                     lazy::Line::Directive(buf)
@@ -163,7 +260,7 @@ where
                 Ok(n)
             }
             Some(LineType::Record) => {
-                let (n, bounds) = read_lazy_record(&mut self.inner, &mut buf)?;
+                let (n, bounds) = read_lazy_record(&mut self.limited(), &mut buf)?;
                 *line = lazy::Line::Record(lazy::Record { buf, bounds });
                 Ok(n)
             }
@@ -195,6 +292,145 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self.lines())
     }
+
+    /// Returns an iterator over records, directives, and comments starting from the current
+    /// stream position.
+    ///
+    /// Unlike [`Self::records`], directives and comments are also yielded, in the order they
+    /// appear. It stops at either EOF or when the `FASTA` directive is read, whichever comes
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ###FASTA
+    /// >sq0
+    /// ACGT
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// let mut lines = reader.records_and_directives();
+    ///
+    /// assert!(matches!(lines.next().transpose()?, Some(gff::Line::Directive(_))));
+    /// assert!(matches!(lines.next().transpose()?, Some(gff::Line::Record(_))));
+    /// assert!(lines.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_and_directives(&mut self) -> RecordsAndDirectives<'_, R> {
+        RecordsAndDirectives::new(self.lines())
+    }
+
+    /// Returns an iterator over records starting from the current stream position, skipping
+    /// duplicate records.
+    ///
+    /// This is the same as [`Self::records`] but suppresses records that are identical to a
+    /// previously yielded record, i.e., have the same reference sequence name, source, type,
+    /// start, end, strand, and attributes.
+    ///
+    /// `window_size` is the number of most recently yielded records that are remembered for
+    /// comparison. It bounds memory usage, but duplicates that are farther apart than the window
+    /// are not suppressed. A window size of 0 disables deduplication.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    /// let mut records = reader.records_dedup(16);
+    ///
+    /// assert!(records.next().transpose()?.is_some());
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_dedup(&mut self, window_size: usize) -> DedupRecords<'_, R> {
+        DedupRecords::new(self.records(), window_size)
+    }
+
+    /// Returns an iterator over records of the bundled FASTA section.
+    ///
+    /// The stream is expected to be directly after the `FASTA` directive, e.g., after
+    /// [`Self::records`] is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+    /// ###FASTA
+    /// >sq0
+    /// ACGT
+    /// ";
+    /// let mut reader = gff::Reader::new(&data[..]);
+    ///
+    /// for result in reader.records() {
+    ///     let _ = result?;
+    /// }
+    ///
+    /// let mut fasta_records = reader.fasta_records();
+    /// let record = fasta_records.next().transpose()?.expect("missing record");
+    /// assert_eq!(record.name(), b"sq0");
+    /// assert_eq!(record.sequence().as_ref(), b"ACGT");
+    /// assert!(fasta_records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn fasta_records(&mut self) -> FastaRecords<'_, R> {
+        FastaRecords::new(LineLimited::new(&mut self.inner, self.max_line_length))
+    }
+
+    fn limited(&mut self) -> LineLimited<&mut R> {
+        LineLimited::new(&mut self.inner, self.max_line_length)
+    }
+}
+
+impl<'r> Reader<Box<dyn BufRead + 'r>> {
+    /// Creates a GFF reader that transparently decompresses gzip input.
+    ///
+    /// If the input starts with the gzip magic number, it is decompressed as a (multi-member)
+    /// gzip stream, which includes BGZF. Otherwise, it is read as is. The resulting reader does
+    /// not support indexed queries; use a `Reader<bgzf::Reader<R>>` for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"##gff-version 3\n";
+    /// let mut reader = gff::Reader::new_autodetect(&data[..])?;
+    /// assert_eq!(reader.lines().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new_autodetect<R>(mut inner: R) -> io::Result<Self>
+    where
+        R: BufRead + 'r,
+    {
+        use flate2::bufread::MultiGzDecoder;
+
+        const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+        let src = inner.fill_buf()?;
+
+        let inner: Box<dyn BufRead + 'r> = if src.starts_with(&GZIP_MAGIC_NUMBER) {
+            Box::new(BufReader::new(MultiGzDecoder::new(inner)))
+        } else {
+            Box::new(inner)
+        };
+
+        Ok(Self::new(inner))
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -210,29 +446,10 @@ where
     where
         I: BinningIndex,
     {
-        let header = index
-            .header()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
-
-        let region_name = str::from_utf8(region.name())
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region_name)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "missing reference sequence name",
-                )
-            })?;
-
-        let chunks = index.query(reference_sequence_id, region.interval())?;
+        let max_line_length = self.max_line_length;
 
-        let records = csi::io::Query::new(&mut self.inner, chunks)
-            .indexed_records(header)
-            .filter_by_region(region)
-            .map(|result| {
+        let records =
+            query_indexed_records(&mut self.inner, max_line_length, index, region)?.map(|result| {
                 result.and_then(|r| {
                     r.as_ref()
                         .parse()
@@ -242,6 +459,160 @@ where
 
         Ok(records)
     }
+
+    /// Returns a query planner for issuing multiple region queries.
+    ///
+    /// The planner reuses the index header and caches resolved reference sequence IDs across
+    /// queries.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut reader = File::open("annotations.gff3.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gff::Reader::new)?;
+    ///
+    /// let index = csi::read("annotations.gff3.gz.csi")?;
+    /// let mut planner = reader.query_planner(&index)?;
+    ///
+    /// for raw_region in ["sq0:8-13", "sq1:21-34"] {
+    ///     let region = raw_region.parse()?;
+    ///
+    ///     for result in planner.query(&region)? {
+    ///         let record = result?;
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_planner<'r, I>(&'r mut self, index: &'r I) -> io::Result<QueryPlanner<'r, R, I>>
+    where
+        I: BinningIndex,
+    {
+        QueryPlanner::new(self, index)
+    }
+
+    /// Counts the records that intersect the given region.
+    ///
+    /// This is the same as counting the records returned by [`Self::query`] but does not fully
+    /// parse each record.
+    pub fn count_query<I>(&mut self, index: &I, region: &Region) -> io::Result<usize>
+    where
+        I: BinningIndex,
+    {
+        query_indexed_records(&mut self.inner, self.max_line_length, index, region)?
+            .try_fold(0, |n, result| result.map(|_| n + 1))
+    }
+
+    /// Returns an iterator over records that intersects the given region, along with the start
+    /// virtual position of each record.
+    ///
+    /// Seeking the underlying reader to a returned position rereads the same record.
+    pub fn query_with_positions<'r, I>(
+        &'r mut self,
+        index: &I,
+        region: &'r Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<(bgzf::VirtualPosition, Record)>> + 'r>
+    where
+        I: BinningIndex,
+    {
+        const COMMENT_PREFIX: char = '#';
+
+        let max_line_length = self.max_line_length;
+        let (_, chunks) = query_chunks(index, region)?;
+        let mut query = csi::io::Query::new(&mut self.inner, chunks);
+        let mut buf = String::new();
+
+        Ok(iter::from_fn(move || loop {
+            // This moves the reader to the start of the next chunk, if needed.
+            match query.fill_buf() {
+                Ok([]) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            let position = query.virtual_position();
+
+            buf.clear();
+
+            if let Err(e) = read_line(&mut LineLimited::new(&mut query, max_line_length), &mut buf)
+            {
+                return Some(Err(e));
+            }
+
+            if buf.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            let record: Record = match buf.parse() {
+                Ok(record) => record,
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if intersects(&record, region) {
+                return Some(Ok((position, record)));
+            }
+        }))
+    }
+}
+
+fn query_chunks<'i, I>(index: &'i I, region: &Region) -> io::Result<(&'i Header, Vec<Chunk>)>
+where
+    I: BinningIndex,
+{
+    let header = index
+        .header()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+    let region_name = str::from_utf8(region.name())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let reference_sequence_id = header
+        .reference_sequence_names()
+        .get_index_of(region_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })?;
+
+    let chunks = index.query(reference_sequence_id, region.interval())?;
+
+    Ok((header, chunks))
+}
+
+fn query_indexed_records<'r, R, I>(
+    reader: &'r mut bgzf::Reader<R>,
+    max_line_length: Option<usize>,
+    index: &I,
+    region: &'r Region,
+) -> io::Result<impl Iterator<Item = io::Result<impl AsRef<str>>> + 'r>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    let (header, chunks) = query_chunks(index, region)?;
+    let query = csi::io::Query::new(reader, chunks);
+
+    Ok(
+        csi::io::IndexedRecords::new(LineLimited::new(query, max_line_length), header)
+            .filter_by_region(region),
+    )
+}
+
+fn intersects(record: &Record, region: &Region) -> bool {
+    use noodles_core::region::Interval;
+
+    let interval = Interval::from(record.start()..=record.end());
+
+    record.reference_sequence_name().as_bytes() == region.name()
+        && interval.intersects(region.interval())
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -265,6 +636,30 @@ where
     }
 }
 
+fn discard_line<R>(reader: &mut R) -> io::Result<()>
+where
+    R: BufRead,
+{
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            return Ok(());
+        }
+
+        match src.iter().position(|&b| b == LINE_FEED as u8) {
+            Some(i) => {
+                reader.consume(i + 1);
+                return Ok(());
+            }
+            None => {
+                let n = src.len();
+                reader.consume(n);
+            }
+        }
+    }
+}
+
 enum LineType {
     Comment,
     Record,
@@ -384,6 +779,97 @@ sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
         Ok(())
     }
 
+    #[test]
+    fn test_records_and_directives() -> io::Result<()> {
+        use crate::{directive::GffVersion, Directive, Line};
+
+        let data = b"\
+##gff-version 3
+#format: gff3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+##sequence-region sq0 1 21
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let lines: Vec<_> = reader.records_and_directives().collect::<io::Result<_>>()?;
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[0],
+            Line::Directive(Directive::GffVersion(GffVersion::default()))
+        );
+        assert_eq!(lines[1], Line::Comment(String::from("format: gff3")));
+        assert!(matches!(lines[2], Line::Record(_)));
+        assert!(matches!(
+            lines[3],
+            Line::Directive(Directive::SequenceRegion(_))
+        ));
+        assert!(matches!(lines[4], Line::Record(_)));
+
+        let records: Vec<_> = reader.fasta_records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header() -> io::Result<()> {
+        use crate::directive::{GffVersion, SequenceRegion};
+
+        let data = b"\
+##gff-version 3
+#format: gff3
+##sequence-region sq0 1 21
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        assert_eq!(header.gff_version(), Some(&GffVersion::default()));
+        assert_eq!(
+            header.sequence_regions(),
+            [SequenceRegion::new(String::from("sq0"), 1, 21)]
+        );
+        assert!(header.other_directives().is_empty());
+        assert_eq!(header.comments(), [String::from("format: gff3")]);
+
+        let mut buf = String::new();
+        reader.read_line(&mut buf)?;
+        assert!(buf.starts_with("sq0\tNOODLES\tgene"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_autodetect() -> io::Result<()> {
+        use std::io::Write;
+
+        use flate2::{write::GzEncoder, Compression};
+
+        const DATA: &[u8] = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+";
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        let compressed_data = encoder.finish()?;
+
+        for src in [&compressed_data[..], DATA] {
+            let mut reader = Reader::new_autodetect(src)?;
+            let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+            assert_eq!(records.len(), 1);
+            assert_eq!(records[0].reference_sequence_name(), "sq0");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_records_with_fasta_directive() -> io::Result<()> {
         let data = b"\
@@ -404,6 +890,36 @@ ACGT
 
         assert_eq!(n, 1);
 
+        let records: Vec<_> = reader.fasta_records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name(), b"sq0");
+        assert_eq!(records[0].sequence().as_ref(), b"ACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_dedup() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tgene_id=ndls1;gene_name=gene1
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records_dedup(2).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records_dedup(1).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 3);
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records_dedup(0).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 4);
+
         Ok(())
     }
 
@@ -424,4 +940,269 @@ ACGT
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_line_with_max_line_length() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_max_line_length(Some(16));
+
+        let mut buf = String::new();
+        reader.read_line(&mut buf)?;
+        assert_eq!(buf, "##gff-version 3");
+
+        buf.clear();
+        assert!(matches!(
+            reader.read_line(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // The long line is discarded.
+        assert!(reader.lines().next().is_none());
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_max_line_length(Some(14));
+        let mut lines = reader.lines();
+        assert!(matches!(
+            lines.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(lines.next(), Some(Err(_))));
+        assert!(lines.next().is_none());
+
+        let mut reader = Reader::new(&b"noodles\r\n"[..]);
+        reader.set_max_line_length(Some(7));
+        buf.clear();
+        assert_eq!(reader.read_line(&mut buf)?, 9);
+        assert_eq!(buf, "noodles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lazy_line_with_max_line_length() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+##FASTA
+";
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_max_line_length(Some(16));
+
+        let mut line = lazy::Line::default();
+        reader.read_lazy_line(&mut line)?;
+        assert!(matches!(line, lazy::Line::Directive(_)));
+
+        assert!(matches!(
+            reader.read_lazy_line(&mut line),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // The long line is discarded.
+        reader.read_lazy_line(&mut line)?;
+        assert!(matches!(line, lazy::Line::Directive(ref s) if s == "##FASTA"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_records_with_max_line_length() -> io::Result<()> {
+        let data = b"\
+##FASTA
+>sq0
+ACGTACGTACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_max_line_length(Some(8));
+
+        let mut buf = String::new();
+        reader.read_line(&mut buf)?;
+
+        assert!(matches!(
+            reader.fasta_records().next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    fn build_indexed_records() -> Result<(Vec<u8>, csi::Index), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use noodles_core::Position;
+        use noodles_csi::binning_index::{
+            index::{
+                reference_sequence::{bin::Chunk, index::BinnedIndex},
+                Header,
+            },
+            Indexer,
+        };
+
+        const LINES: [(&str, usize, usize, usize); 4] = [
+            ("sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g0\n", 0, 8, 13),
+            ("sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=g1\n", 0, 21, 34),
+            ("sq0\tNOODLES\tgene\t55\t89\t.\t+\t.\tID=g2\n", 0, 55, 89),
+            ("sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g3\n", 1, 8, 13),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        let mut indexer = Indexer::<BinnedIndex>::default().set_header(
+            Header::builder()
+                .set_reference_sequence_names(
+                    [String::from("sq0"), String::from("sq1")]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        );
+
+        for (line, reference_sequence_id, start, end) in LINES {
+            let start_position = writer.virtual_position();
+            writer.write_all(line.as_bytes())?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                Some((
+                    reference_sequence_id,
+                    Position::try_from(start)?,
+                    Position::try_from(end)?,
+                    true,
+                )),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build(2);
+
+        Ok((data, index))
+    }
+
+    #[test]
+    fn test_query_with_max_line_length() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        fn is_invalid_data<T>(result: Option<io::Result<T>>) -> bool {
+            matches!(result, Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData)
+        }
+
+        let (data, index) = build_indexed_records()?;
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        reader.set_max_line_length(Some(8));
+
+        let region = "sq0".parse()?;
+
+        assert!(is_invalid_data(reader.query(&index, &region)?.next()));
+
+        assert!(matches!(
+            reader.count_query(&index, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(is_invalid_data(
+            reader.query_with_positions(&index, &region)?.next()
+        ));
+
+        let mut planner = reader.query_planner(&index)?;
+        assert!(is_invalid_data(planner.query(&region)?.next()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let (data, index) = build_indexed_records()?;
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        for raw_region in ["sq0", "sq0:10-30", "sq0:40-50", "sq1"] {
+            let region = raw_region.parse()?;
+            let expected = reader.query(&index, &region)?.count();
+            assert_eq!(reader.count_query(&index, &region)?, expected);
+        }
+
+        let region = "sq0:10-30".parse()?;
+        assert_eq!(reader.count_query(&index, &region)?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_planner() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let (data, index) = build_indexed_records()?;
+
+        let regions: Vec<Region> = ["sq0:10-30", "sq1", "sq0:10-30"]
+            .into_iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(&data)));
+        let mut expected = Vec::new();
+
+        for region in &regions {
+            let records: Vec<_> = reader.query(&index, region)?.collect::<io::Result<_>>()?;
+            expected.push(records);
+        }
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(&data)));
+        let mut planner = reader.query_planner(&index)?;
+        let mut actual = Vec::new();
+
+        for region in &regions {
+            let records: Vec<_> = planner.query(region)?.collect::<io::Result<_>>()?;
+            actual.push(records);
+        }
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual[0].len(), 2);
+        assert_eq!(actual[1].len(), 1);
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            planner.query(&region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_positions() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let (data, index) = build_indexed_records()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(&data)));
+        let region = "sq0".parse()?;
+        let records: Vec<_> = reader
+            .query_with_positions(&index, &region)?
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 3);
+        assert!(records.windows(2).all(|w| w[0].0 <= w[1].0));
+
+        let expected: Vec<_> = reader.query(&index, &region)?.collect::<io::Result<_>>()?;
+        let actual: Vec<_> = records.iter().map(|(_, record)| record.clone()).collect();
+        assert_eq!(actual, expected);
+
+        for (position, record) in records {
+            let mut inner = bgzf::Reader::new(Cursor::new(&data));
+            inner.seek(position)?;
+
+            let mut reader = Reader::new(inner);
+            let actual = reader.records().next().transpose()?;
+            assert_eq!(actual, Some(record));
+        }
+
+        Ok(())
+    }
 }
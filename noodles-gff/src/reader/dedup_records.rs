@@ -0,0 +1,74 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+use crate::Record;
+
+use super::Records;
+
+/// An iterator over records of a GFF reader that suppresses duplicate records.
+///
+/// Two records are duplicates if they have the same reference sequence name, source, type, start,
+/// end, strand, and attributes. Only the most recent `window_size` distinct records are
+/// remembered, so duplicates farther apart than the window are yielded again.
+///
+/// This is created by calling [`crate::Reader::records_dedup`].
+pub struct DedupRecords<'a, R> {
+    records: Records<'a, R>,
+    window: VecDeque<Record>,
+    window_size: usize,
+}
+
+impl<'a, R> DedupRecords<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(records: Records<'a, R>, window_size: usize) -> Self {
+        Self {
+            records,
+            window: VecDeque::with_capacity(window_size),
+            window_size,
+        }
+    }
+}
+
+impl<'a, R> Iterator for DedupRecords<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if self.window.iter().any(|r| is_duplicate(r, &record)) {
+                continue;
+            }
+
+            if self.window_size > 0 {
+                if self.window.len() == self.window_size {
+                    self.window.pop_front();
+                }
+
+                self.window.push_back(record.clone());
+            }
+
+            return Some(Ok(record));
+        }
+    }
+}
+
+fn is_duplicate(a: &Record, b: &Record) -> bool {
+    a.reference_sequence_name() == b.reference_sequence_name()
+        && a.source() == b.source()
+        && a.ty() == b.ty()
+        && a.start() == b.start()
+        && a.end() == b.end()
+        && a.strand() == b.strand()
+        && a.attributes() == b.attributes()
+}
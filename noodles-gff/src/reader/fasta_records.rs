@@ -0,0 +1,34 @@
+use std::io::{self, BufRead};
+
+use noodles_fasta as fasta;
+
+use super::line_limited::LineLimited;
+
+/// An iterator over records of the bundled FASTA section of a GFF reader.
+///
+/// This is created by calling [`crate::Reader::fasta_records`].
+pub struct FastaRecords<'a, R> {
+    inner: fasta::Reader<LineLimited<&'a mut R>>,
+}
+
+impl<'a, R> FastaRecords<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: LineLimited<&'a mut R>) -> Self {
+        Self {
+            inner: fasta::Reader::new(inner),
+        }
+    }
+}
+
+impl<'a, R> Iterator for FastaRecords<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<fasta::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.records().next()
+    }
+}
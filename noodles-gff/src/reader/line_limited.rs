@@ -0,0 +1,103 @@
+use std::io::{self, BufRead, Read};
+
+use super::{discard_line, CARRIAGE_RETURN, LINE_FEED};
+
+/// A reader that fails on lines longer than a maximum length.
+///
+/// The line length excludes the line terminator (`\n` or `\r\n`). When a line is too long, the
+/// rest of it is discarded, so the next read starts at the following line. If no maximum is set,
+/// this reads from the inner reader as is.
+pub(crate) struct LineLimited<R> {
+    inner: R,
+    max_line_length: Option<usize>,
+    line_len: usize,
+    // The length of the last filled buffer and whether it ends the current line.
+    buf_len: usize,
+    is_eol: bool,
+}
+
+impl<R> LineLimited<R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(inner: R, max_line_length: Option<usize>) -> Self {
+        Self {
+            inner,
+            max_line_length,
+            line_len: 0,
+            buf_len: 0,
+            is_eol: false,
+        }
+    }
+}
+
+impl<R> Read for LineLimited<R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let src = self.fill_buf()?;
+        let n = src.len().min(buf.len());
+        buf[..n].copy_from_slice(&src[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R> BufRead for LineLimited<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let Some(max_line_length) = self.max_line_length else {
+            return self.inner.fill_buf();
+        };
+
+        let src = self.inner.fill_buf()?;
+
+        let (n, is_eol, line_len) = match src.iter().position(|&b| b == LINE_FEED as u8) {
+            Some(i) => {
+                let has_carriage_return = i > 0 && src[i - 1] == CARRIAGE_RETURN as u8;
+                let len = if has_carriage_return { i - 1 } else { i };
+                (i + 1, true, self.line_len + len)
+            }
+            None if src.is_empty() => (0, false, self.line_len),
+            // A trailing carriage return may still be part of the line terminator.
+            None => (
+                src.len(),
+                false,
+                (self.line_len + src.len()).saturating_sub(1),
+            ),
+        };
+
+        if line_len > max_line_length {
+            discard_line(&mut self.inner)?;
+            self.line_len = 0;
+
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line length exceeds maximum ({max_line_length})"),
+            ));
+        }
+
+        self.buf_len = n;
+        self.is_eol = is_eol;
+
+        let src = self.inner.fill_buf()?;
+        Ok(&src[..n])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.max_line_length.is_some() {
+            if self.is_eol && amt >= self.buf_len {
+                self.line_len = 0;
+            } else {
+                self.line_len += amt;
+            }
+
+            self.buf_len = self.buf_len.saturating_sub(amt);
+        }
+
+        self.inner.consume(amt);
+    }
+}
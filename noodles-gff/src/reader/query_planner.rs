@@ -0,0 +1,94 @@
+use std::{
+    collections::HashMap,
+    io::{self, Read, Seek},
+    str,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{self as csi, binning_index::index::Header, io::IndexedRecords, BinningIndex};
+
+use super::{line_limited::LineLimited, Reader};
+use crate::Record;
+
+/// A GFF query planner.
+///
+/// This reuses the index header and caches resolved reference sequence IDs across queries, which
+/// is useful when issuing many region queries.
+///
+/// This is created by calling [`Reader::query_planner`].
+pub struct QueryPlanner<'r, R, I> {
+    reader: &'r mut Reader<bgzf::Reader<R>>,
+    index: &'r I,
+    header: &'r Header,
+    reference_sequence_ids: HashMap<Vec<u8>, usize>,
+}
+
+impl<'r, R, I> QueryPlanner<'r, R, I>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    pub(super) fn new(reader: &'r mut Reader<bgzf::Reader<R>>, index: &'r I) -> io::Result<Self> {
+        let header = index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        Ok(Self {
+            reader,
+            index,
+            header,
+            reference_sequence_ids: HashMap::new(),
+        })
+    }
+
+    /// Returns an iterator over records that intersects the given region.
+    ///
+    /// This returns the same records as [`Reader::query`].
+    pub fn query<'a>(
+        &'a mut self,
+        region: &'a Region,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'a> {
+        let reference_sequence_id = self.resolve_reference_sequence_id(region.name())?;
+        let chunks = self.index.query(reference_sequence_id, region.interval())?;
+
+        let query = csi::io::Query::new(&mut self.reader.inner, chunks);
+        let max_line_length = self.reader.max_line_length;
+
+        let records = IndexedRecords::new(LineLimited::new(query, max_line_length), self.header)
+            .filter_by_region(region)
+            .map(|result| {
+                result.and_then(|r| {
+                    r.as_ref()
+                        .parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+            });
+
+        Ok(records)
+    }
+
+    fn resolve_reference_sequence_id(&mut self, name: &[u8]) -> io::Result<usize> {
+        if let Some(id) = self.reference_sequence_ids.get(name) {
+            return Ok(*id);
+        }
+
+        let region_name =
+            str::from_utf8(name).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let id = self
+            .header
+            .reference_sequence_names()
+            .get_index_of(region_name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "missing reference sequence name",
+                )
+            })?;
+
+        self.reference_sequence_ids.insert(name.to_vec(), id);
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,38 @@
+use std::io::{self, BufRead};
+
+use crate::{Directive, Line};
+
+use super::Lines;
+
+/// Returns an iterator over records, directives, and comments of a GFF reader.
+///
+/// This is the same as [`Lines`] but stops at either EOF or when the `FASTA` directive is read,
+/// whichever comes first. The `FASTA` directive itself is not yielded.
+///
+/// This is created by calling [`crate::Reader::records_and_directives`].
+pub struct RecordsAndDirectives<'a, R> {
+    lines: Lines<'a, R>,
+}
+
+impl<'a, R> RecordsAndDirectives<'a, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(lines: Lines<'a, R>) -> Self {
+        Self { lines }
+    }
+}
+
+impl<'a, R> Iterator for RecordsAndDirectives<'a, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.lines.next()? {
+            Ok(Line::Directive(Directive::StartOfFasta)) => None,
+            result => Some(result),
+        }
+    }
+}
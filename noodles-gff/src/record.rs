@@ -5,9 +5,11 @@ mod builder;
 mod field;
 mod phase;
 pub mod strand;
+pub mod target;
 
 pub use self::{
     attributes::Attributes, builder::Builder, field::Field, phase::Phase, strand::Strand,
+    target::Target,
 };
 
 use std::{error, fmt, num, str::FromStr};
@@ -172,6 +174,36 @@ impl Record {
     pub fn attributes(&self) -> &Attributes {
         &self.attributes
     }
+
+    /// Returns the target of an alignment feature (`Target`).
+    ///
+    /// This returns `None` if the record does not have a `Target` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff::{self as gff, record::Strand};
+    ///
+    /// let record: gff::Record = "sq0\tNOODLES\tmatch\t8\t13\t.\t+\t.\tTarget=seq1 10 20 +".parse()?;
+    /// let target = record.target().transpose()?.unwrap();
+    ///
+    /// assert_eq!(target.id(), "seq1");
+    /// assert_eq!(target.start(), Position::try_from(10)?);
+    /// assert_eq!(target.end(), Position::try_from(20)?);
+    /// assert_eq!(target.strand(), Some(Strand::Forward));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn target(&self) -> Option<Result<Target, target::ParseError>> {
+        use self::attributes::field::tag;
+
+        self.attributes().get(tag::TARGET).map(|value| {
+            value
+                .as_string()
+                .ok_or(target::ParseError::InvalidValue)
+                .and_then(|s| s.parse())
+        })
+    }
 }
 
 impl Default for Record {
@@ -355,6 +387,29 @@ fn parse_reference_sequence_name(s: &str) -> Result<String, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_target() -> Result<(), Box<dyn std::error::Error>> {
+        let record: Record = "sq0\tNOODLES\tmatch\t8\t13\t.\t+\t.\tTarget=seq1 10 20 +".parse()?;
+        let expected = Target::new(
+            String::from("seq1"),
+            Position::try_from(10)?,
+            Position::try_from(20)?,
+            Some(Strand::Forward),
+        );
+        assert_eq!(record.target(), Some(Ok(expected)));
+
+        let record: Record = "sq0\tNOODLES\tmatch\t8\t13\t.\t+\t.\tTarget=seq1 20 10".parse()?;
+        assert!(matches!(
+            record.target(),
+            Some(Err(target::ParseError::InvalidInterval(..)))
+        ));
+
+        let record = Record::default();
+        assert!(record.target().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         let record = Record::default();
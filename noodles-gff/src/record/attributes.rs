@@ -13,6 +13,7 @@ use indexmap::IndexMap;
 use self::field::{Tag, Value};
 
 const DELIMITER: char = ';';
+const MISSING: &str = ".";
 
 /// GFF record attributes.
 ///
@@ -100,24 +101,53 @@ impl FromStr for Attributes {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use self::field::parse_field;
+        parse(s)
+    }
+}
 
-        if s.is_empty() {
-            return Ok(Self::default());
-        }
+/// Parses a raw GFF record attributes column.
+///
+/// Keys and values are percent-decoded, and values with commas are parsed as arrays. A missing
+/// column (`.`) and an empty string are parsed as empty attributes. Trailing delimiters (`;`) are
+/// ignored, and values of duplicate keys are merged into a single array.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff::record::attributes::{self, field::{Tag, Value}};
+///
+/// let attributes = attributes::parse("ID=ndls0;Parent=gene0,gene1;Note=8%2C13;")?;
+///
+/// assert_eq!(attributes.get("ID"), Some(&Value::from("ndls0")));
+/// assert_eq!(
+///     attributes.get("Parent"),
+///     Some(&Value::from(vec![String::from("gene0"), String::from("gene1")]))
+/// );
+/// assert_eq!(attributes.get("Note"), Some(&Value::from("8,13")));
+///
+/// assert!(attributes::parse(".")?.is_empty());
+/// # Ok::<_, attributes::ParseError>(())
+/// ```
+pub fn parse(s: &str) -> Result<Attributes, ParseError> {
+    use self::field::parse_field;
+
+    let s = s.trim_end_matches(DELIMITER);
 
-        let mut map: IndexMap<Tag, Value> = IndexMap::new();
+    if s.is_empty() || s == MISSING {
+        return Ok(Attributes::default());
+    }
 
-        for raw_field in s.split(DELIMITER) {
-            let (key, value) = parse_field(raw_field).map_err(ParseError::InvalidField)?;
+    let mut map: IndexMap<Tag, Value> = IndexMap::new();
 
-            map.entry(key)
-                .and_modify(|v| v.extend(value.iter().cloned()))
-                .or_insert(value);
-        }
+    for raw_field in s.split(DELIMITER) {
+        let (key, value) = parse_field(raw_field).map_err(ParseError::InvalidField)?;
 
-        Ok(Self(map))
+        map.entry(key)
+            .and_modify(|v| v.extend(value.iter().cloned()))
+            .or_insert(value);
     }
+
+    Ok(Attributes(map))
 }
 
 #[cfg(test)]
@@ -178,4 +208,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse() -> Result<(), ParseError> {
+        assert!(parse("")?.is_empty());
+        assert!(parse(".")?.is_empty());
+        assert!(parse(";")?.is_empty());
+
+        let expected: Attributes = [(Tag::from("ID"), Value::from("ndls0"))]
+            .into_iter()
+            .collect();
+        assert_eq!(parse("ID=ndls0;")?, expected);
+        assert_eq!(parse("ID=ndls0;;")?, expected);
+
+        let actual = parse("ID=ndls0;Parent=gene0,gene1")?;
+        let expected = [
+            (Tag::from("ID"), Value::from("ndls0")),
+            (
+                Tag::from("Parent"),
+                Value::from(vec![String::from("gene0"), String::from("gene1")]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+
+        let actual = parse("Parent=gene0;Parent=gene1,gene2")?;
+        let expected = [(
+            Tag::from("Parent"),
+            Value::from(vec![
+                String::from("gene0"),
+                String::from("gene1"),
+                String::from("gene2"),
+            ]),
+        )]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+
+        let actual = parse("%25s=8%2C13;Note=a%3Bb%3Dc")?;
+        let expected = [
+            (Tag::from("%s"), Value::from("8,13")),
+            (Tag::from("Note"), Value::from("a;b=c")),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            parse("ID"),
+            Err(ParseError::InvalidField(field::ParseError::Invalid))
+        ));
+        assert!(matches!(
+            parse("ID=ndls0;;Name=gene0"),
+            Err(ParseError::InvalidField(field::ParseError::Invalid))
+        ));
+
+        Ok(())
+    }
 }
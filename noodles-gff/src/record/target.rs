@@ -0,0 +1,220 @@
+//! GFF record target.
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::Position;
+
+use super::Strand;
+
+const DELIMITER: char = ' ';
+
+/// A GFF record target (`Target`).
+///
+/// This is the target of an alignment feature, i.e., `target_id start end [strand]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Target {
+    id: String,
+    start: Position,
+    end: Position,
+    strand: Option<Strand>,
+}
+
+impl Target {
+    /// Creates a target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff::record::{Strand, Target};
+    ///
+    /// let target = Target::new(
+    ///     String::from("seq1"),
+    ///     Position::try_from(10)?,
+    ///     Position::try_from(20)?,
+    ///     Some(Strand::Forward),
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new(id: String, start: Position, end: Position, strand: Option<Strand>) -> Self {
+        Self {
+            id,
+            start,
+            end,
+            strand,
+        }
+    }
+
+    /// Returns the target ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the strand, if set.
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+}
+
+/// An error returned when a raw GFF record target fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The value is not a single string.
+    InvalidValue,
+    /// The ID is missing.
+    MissingId,
+    /// The start is missing.
+    MissingStart,
+    /// The start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The end is missing.
+    MissingEnd,
+    /// The end is invalid.
+    InvalidEnd(num::ParseIntError),
+    /// The start is greater than the end.
+    InvalidInterval(Position, Position),
+    /// The strand is invalid.
+    InvalidStrand(String),
+    /// There are unexpected trailing fields.
+    UnexpectedInput,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStart(e) | Self::InvalidEnd(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidValue => f.write_str("invalid value"),
+            Self::MissingId => f.write_str("missing ID"),
+            Self::MissingStart => f.write_str("missing start"),
+            Self::InvalidStart(_) => f.write_str("invalid start"),
+            Self::MissingEnd => f.write_str("missing end"),
+            Self::InvalidEnd(_) => f.write_str("invalid end"),
+            Self::InvalidInterval(start, end) => {
+                write!(f, "invalid interval: expected {start} <= {end}")
+            }
+            Self::InvalidStrand(s) => write!(f, "expected {{+, -}}, got {s}"),
+            Self::UnexpectedInput => f.write_str("unexpected input"),
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut fields = s.split(DELIMITER);
+
+        let id = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingId)?;
+
+        let start = fields
+            .next()
+            .ok_or(ParseError::MissingStart)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStart))?;
+
+        let end = fields
+            .next()
+            .ok_or(ParseError::MissingEnd)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidEnd))?;
+
+        if start > end {
+            return Err(ParseError::InvalidInterval(start, end));
+        }
+
+        let strand = fields.next().map(parse_strand).transpose()?;
+
+        if fields.next().is_some() {
+            return Err(ParseError::UnexpectedInput);
+        }
+
+        Ok(Self::new(id.into(), start, end, strand))
+    }
+}
+
+fn parse_strand(s: &str) -> Result<Strand, ParseError> {
+    match s {
+        "+" => Ok(Strand::Forward),
+        "-" => Ok(Strand::Reverse),
+        _ => Err(ParseError::InvalidStrand(s.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(10)?;
+        let end = Position::try_from(20)?;
+
+        assert_eq!(
+            "seq1 10 20 +".parse(),
+            Ok(Target::new(
+                String::from("seq1"),
+                start,
+                end,
+                Some(Strand::Forward)
+            ))
+        );
+
+        assert_eq!(
+            "seq1 10 20".parse(),
+            Ok(Target::new(String::from("seq1"), start, end, None))
+        );
+
+        assert_eq!("".parse::<Target>(), Err(ParseError::Empty));
+        assert_eq!(" 10 20".parse::<Target>(), Err(ParseError::MissingId));
+        assert_eq!("seq1".parse::<Target>(), Err(ParseError::MissingStart));
+        assert!(matches!(
+            "seq1 ndls 20".parse::<Target>(),
+            Err(ParseError::InvalidStart(_))
+        ));
+        assert_eq!("seq1 10".parse::<Target>(), Err(ParseError::MissingEnd));
+        assert!(matches!(
+            "seq1 10 0".parse::<Target>(),
+            Err(ParseError::InvalidEnd(_))
+        ));
+        assert_eq!(
+            "seq1 20 10".parse::<Target>(),
+            Err(ParseError::InvalidInterval(end, start))
+        );
+        assert_eq!(
+            "seq1 10 20 .".parse::<Target>(),
+            Err(ParseError::InvalidStrand(String::from(".")))
+        );
+        assert_eq!(
+            "seq1 10 20 + ndls".parse::<Target>(),
+            Err(ParseError::UnexpectedInput)
+        );
+
+        Ok(())
+    }
+}
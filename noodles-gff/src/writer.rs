@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use super::{Directive, Line, Record};
+use super::{lazy, Directive, Line, Record};
 
 /// A GFF writer.
 pub struct Writer<W> {
@@ -67,6 +67,38 @@ where
         writeln!(self.inner, "{line}")
     }
 
+    /// Writes a lazy GFF line.
+    ///
+    /// The line is written as it was read, followed by a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff::{self as gff, lazy};
+    ///
+    /// let mut writer = gff::Writer::new(Vec::new());
+    ///
+    /// let line = lazy::Line::Directive(String::from("##gff-version 3"));
+    /// writer.write_lazy_line(&line)?;
+    ///
+    /// let line = lazy::Line::Comment(String::from("#noodles"));
+    /// writer.write_lazy_line(&line)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"##gff-version 3\n#noodles\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_lazy_line(&mut self, line: &lazy::Line) -> io::Result<()> {
+        match line {
+            lazy::Line::Directive(s) | lazy::Line::Comment(s) => {
+                self.inner.write_all(s.as_bytes())?;
+            }
+            lazy::Line::Record(record) => write_lazy_record(&mut self.inner, record)?,
+        }
+
+        writeln!(self.inner)
+    }
+
     /// Writes a GFF directive.
     ///
     /// # Examples
@@ -114,3 +146,62 @@ where
         writeln!(self.inner, "{record}")
     }
 }
+
+fn write_lazy_record<W>(writer: &mut W, record: &lazy::Record) -> io::Result<()>
+where
+    W: Write,
+{
+    const DELIMITER: &[u8] = b"\t";
+
+    let buf = &record.buf;
+    let bounds = &record.bounds;
+
+    let fields = [
+        &buf[bounds.reference_sequence_name_range()],
+        &buf[bounds.source_range()],
+        &buf[bounds.type_range()],
+        &buf[bounds.start_range()],
+        &buf[bounds.end_range()],
+        &buf[bounds.score_range()],
+        &buf[bounds.strand_range()],
+        &buf[bounds.phase_range()],
+        &buf[bounds.attributes_range()],
+    ];
+
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(DELIMITER)?;
+        }
+
+        writer.write_all(field.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reader;
+
+    #[test]
+    fn test_write_lazy_line() -> io::Result<()> {
+        const SRC: &[u8] = b"##gff-version 3
+#format: gff3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+sq0\t.\texon\t21\t34\t5.0\t-\t0\t.
+";
+
+        let mut reader = Reader::new(SRC);
+        let mut writer = Writer::new(Vec::new());
+        let mut line = lazy::Line::default();
+
+        while reader.read_lazy_line(&mut line)? != 0 {
+            writer.write_lazy_line(&line)?;
+        }
+
+        assert_eq!(writer.get_ref(), SRC);
+
+        Ok(())
+    }
+}
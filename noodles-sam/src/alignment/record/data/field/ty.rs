@@ -1,5 +1,5 @@
 /// An alignment record data field value type.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Type {
     /// Character (`A`).
     Character,
@@ -24,3 +24,76 @@ pub enum Type {
     /// Array (`B`).
     Array,
 }
+
+impl Type {
+    /// Returns whether the type is an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::Type;
+    /// assert!(Type::Array.is_array());
+    /// assert!(!Type::Int32.is_array());
+    /// ```
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array)
+    }
+}
+
+impl From<Type> for char {
+    /// Converts a type to its type code.
+    ///
+    /// Integer types map to their sized codes (`c`, `C`, `s`, `S`, `i`, `I`). Note that SAM text
+    /// output always uses `i` for integers.
+    fn from(ty: Type) -> Self {
+        match ty {
+            Type::Character => 'A',
+            Type::Int8 => 'c',
+            Type::UInt8 => 'C',
+            Type::Int16 => 's',
+            Type::UInt16 => 'S',
+            Type::Int32 => 'i',
+            Type::UInt32 => 'I',
+            Type::Float => 'f',
+            Type::String => 'Z',
+            Type::Hex => 'H',
+            Type::Array => 'B',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_array() {
+        assert!(Type::Array.is_array());
+
+        assert!(!Type::Character.is_array());
+        assert!(!Type::Int8.is_array());
+        assert!(!Type::UInt8.is_array());
+        assert!(!Type::Int16.is_array());
+        assert!(!Type::UInt16.is_array());
+        assert!(!Type::Int32.is_array());
+        assert!(!Type::UInt32.is_array());
+        assert!(!Type::Float.is_array());
+        assert!(!Type::String.is_array());
+        assert!(!Type::Hex.is_array());
+    }
+
+    #[test]
+    fn test_from_type_for_char() {
+        assert_eq!(char::from(Type::Character), 'A');
+        assert_eq!(char::from(Type::Int8), 'c');
+        assert_eq!(char::from(Type::UInt8), 'C');
+        assert_eq!(char::from(Type::Int16), 's');
+        assert_eq!(char::from(Type::UInt16), 'S');
+        assert_eq!(char::from(Type::Int32), 'i');
+        assert_eq!(char::from(Type::UInt32), 'I');
+        assert_eq!(char::from(Type::Float), 'f');
+        assert_eq!(char::from(Type::String), 'Z');
+        assert_eq!(char::from(Type::Hex), 'H');
+        assert_eq!(char::from(Type::Array), 'B');
+    }
+}
@@ -2,9 +2,9 @@
 
 pub mod array;
 
-use std::io;
+use std::{fmt, io};
 
-use bstr::BStr;
+use bstr::{BStr, ByteSlice};
 
 pub use self::array::Array;
 use super::Type;
@@ -87,6 +87,50 @@ impl<'a> Value<'a> {
     }
 }
 
+impl fmt::Display for Value<'_> {
+    /// Formats the value as it would appear in a SAM record, i.e., without the tag and type.
+    ///
+    /// Values are not validated, so formatting does not fail on values the SAM writer rejects,
+    /// e.g., a NaN float is formatted as `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::Value;
+    /// assert_eq!(Value::Character(b'n').to_string(), "n");
+    /// assert_eq!(Value::Int32(-8).to_string(), "-8");
+    /// assert_eq!(Value::Float(f32::NAN).to_string(), "NaN");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Character(c) => write!(f, "{}", char::from(*c)),
+            Self::Int8(n) => write!(f, "{n}"),
+            Self::UInt8(n) => write!(f, "{n}"),
+            Self::Int16(n) => write!(f, "{n}"),
+            Self::UInt16(n) => write!(f, "{n}"),
+            Self::Int32(n) => write!(f, "{n}"),
+            Self::UInt32(n) => write!(f, "{n}"),
+            Self::Float(n) => fmt_float(f, *n),
+            Self::String(s) | Self::Hex(s) => write!(f, "{s}"),
+            Self::Array(array) => write!(f, "{array}"),
+        }
+    }
+}
+
+// Finite floats use the same representation as the SAM writer. Non-finite floats are formatted
+// as `NaN`, `inf`, or `-inf`.
+fn fmt_float(f: &mut fmt::Formatter<'_>, n: f32) -> fmt::Result {
+    use crate::io::writer::num::write_f32;
+
+    if n.is_finite() {
+        let mut buf = Vec::new();
+        write_f32(&mut buf, n).map_err(|_| fmt::Error)?;
+        write!(f, "{}", buf.as_bstr())
+    } else {
+        write!(f, "{n}")
+    }
+}
+
 impl<'a> TryFrom<Value<'a>> for crate::alignment::record_buf::data::field::Value {
     type Error = io::Error;
 
@@ -106,3 +150,21 @@ impl<'a> TryFrom<Value<'a>> for crate::alignment::record_buf::data::field::Value
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Value::Character(b'n').to_string(), "n");
+        assert_eq!(Value::Character(b'\t').to_string(), "\t");
+        assert_eq!(Value::Int8(-8).to_string(), "-8");
+        assert_eq!(Value::Float(8.0).to_string(), "8");
+        assert_eq!(Value::Float(f32::NAN).to_string(), "NaN");
+        assert_eq!(Value::Float("1e40".parse().unwrap()).to_string(), "inf");
+        assert_eq!(Value::Float(f32::NEG_INFINITY).to_string(), "-inf");
+        assert_eq!(Value::String(BStr::new("nd\tls")).to_string(), "nd\tls");
+        assert_eq!(Value::Hex(BStr::new("CAFE")).to_string(), "CAFE");
+    }
+}
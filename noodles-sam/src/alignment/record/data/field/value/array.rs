@@ -3,7 +3,10 @@
 mod subtype;
 mod values;
 
-use std::{fmt, io};
+use std::{
+    fmt::{self, Write},
+    io,
+};
 
 pub use self::{subtype::Subtype, values::Values};
 
@@ -38,6 +41,40 @@ impl<'a> Array<'a> {
             Array::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns the number of values in the array.
+    pub fn len(&self) -> usize {
+        match self {
+            Array::Int8(values) => values.len(),
+            Array::UInt8(values) => values.len(),
+            Array::Int16(values) => values.len(),
+            Array::UInt16(values) => values.len(),
+            Array::Int32(values) => values.len(),
+            Array::UInt32(values) => values.len(),
+            Array::Float(values) => values.len(),
+        }
+    }
+
+    /// Returns whether the array has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the integer value at the given index, widened to an `i64`.
+    ///
+    /// This returns `None` if the index is out of bounds or if the array is a floating-point
+    /// array. Only the requested value is decoded.
+    pub fn get_i64(&self, i: usize) -> Option<io::Result<i64>> {
+        match self {
+            Array::Int8(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::UInt8(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::Int16(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::UInt16(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::Int32(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::UInt32(values) => values.get(i).map(|result| result.map(i64::from)),
+            Array::Float(_) => None,
+        }
+    }
 }
 
 impl<'a> fmt::Debug for Array<'a> {
@@ -54,6 +91,43 @@ impl<'a> fmt::Debug for Array<'a> {
     }
 }
 
+impl fmt::Display for Array<'_> {
+    /// Formats the array as it would appear in a SAM record, e.g., `i,1,2`.
+    ///
+    /// This returns an error if a value fails to decode.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn fmt_values<'a, N, F>(
+            f: &mut fmt::Formatter<'_>,
+            subtype: char,
+            values: &(dyn Values<'a, N> + 'a),
+            fmt_value: F,
+        ) -> fmt::Result
+        where
+            F: Fn(&mut fmt::Formatter<'_>, N) -> fmt::Result,
+        {
+            f.write_char(subtype)?;
+
+            for result in values.iter() {
+                let n = result.map_err(|_| fmt::Error)?;
+                f.write_char(',')?;
+                fmt_value(f, n)?;
+            }
+
+            Ok(())
+        }
+
+        match self {
+            Self::Int8(values) => fmt_values(f, 'c', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::UInt8(values) => fmt_values(f, 'C', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::Int16(values) => fmt_values(f, 's', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::UInt16(values) => fmt_values(f, 'S', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::Int32(values) => fmt_values(f, 'i', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::UInt32(values) => fmt_values(f, 'I', values.as_ref(), |f, n| write!(f, "{n}")),
+            Self::Float(values) => fmt_values(f, 'f', values.as_ref(), super::fmt_float),
+        }
+    }
+}
+
 impl<'a> TryFrom<Array<'a>> for crate::alignment::record_buf::data::field::value::Array {
     type Error = io::Error;
 
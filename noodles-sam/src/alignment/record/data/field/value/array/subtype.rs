@@ -1,5 +1,5 @@
 /// A alignment record data field array value subtype.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum Subtype {
     /// 8-bit integer (`c`).
     Int8,
@@ -16,3 +16,37 @@ pub enum Subtype {
     /// Single-precision floating-point (`f`).
     Float,
 }
+
+impl Subtype {
+    /// Returns the size of a value of this subtype in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::value::array::Subtype;
+    /// assert_eq!(Subtype::Int16.size(), 2);
+    /// ```
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Int8 | Self::UInt8 => 1,
+            Self::Int16 | Self::UInt16 => 2,
+            Self::Int32 | Self::UInt32 | Self::Float => 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(Subtype::Int8.size(), 1);
+        assert_eq!(Subtype::UInt8.size(), 1);
+        assert_eq!(Subtype::Int16.size(), 2);
+        assert_eq!(Subtype::UInt16.size(), 2);
+        assert_eq!(Subtype::Int32.size(), 4);
+        assert_eq!(Subtype::UInt32.size(), 4);
+        assert_eq!(Subtype::Float.size(), 4);
+    }
+}
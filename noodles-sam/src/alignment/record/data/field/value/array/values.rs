@@ -8,4 +8,11 @@ pub trait Values<'a, N> {
 
     /// Returns an iterator over values.
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<N>> + '_>;
+
+    /// Returns the value at the given index.
+    ///
+    /// By default, this is `self.iter().nth(i)`.
+    fn get(&self, i: usize) -> Option<io::Result<N>> {
+        self.iter().nth(i)
+    }
 }
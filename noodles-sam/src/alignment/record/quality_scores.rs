@@ -8,6 +8,28 @@ pub trait QualityScores {
 
     /// Returns an iterator over scores.
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_>;
+
+    /// Returns the minimum score.
+    ///
+    /// This returns `None` if there are no scores.
+    fn min_score(&self) -> Option<u8> {
+        self.iter().min()
+    }
+
+    /// Returns the mean score.
+    ///
+    /// This returns `None` if there are no scores.
+    fn mean_score(&self) -> Option<f64> {
+        let (n, sum) = self.iter().fold((0u64, 0u64), |(n, sum), score| {
+            (n + 1, sum + u64::from(score))
+        });
+
+        if n == 0 {
+            None
+        } else {
+            Some(sum as f64 / n as f64)
+        }
+    }
 }
 
 impl<'a> IntoIterator for &'a dyn QualityScores {
@@ -37,24 +59,24 @@ impl QualityScores for Box<dyn QualityScores + '_> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_into_iter() {
-        struct T(Vec<u8>);
+    struct T(Vec<u8>);
 
-        impl QualityScores for T {
-            fn is_empty(&self) -> bool {
-                self.0.is_empty()
-            }
+    impl QualityScores for T {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
 
-            fn len(&self) -> usize {
-                self.0.len()
-            }
+        fn len(&self) -> usize {
+            self.0.len()
+        }
 
-            fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
-                Box::new(self.0.iter().copied())
-            }
+        fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
+            Box::new(self.0.iter().copied())
         }
+    }
 
+    #[test]
+    fn test_into_iter() {
         let quality_scores: &dyn QualityScores = &T(vec![45, 35, 43, 50]);
 
         assert_eq!(
@@ -62,4 +84,16 @@ mod tests {
             [45, 35, 43, 50]
         );
     }
+
+    #[test]
+    fn test_min_score() {
+        assert_eq!(T(vec![45, 35, 43, 50]).min_score(), Some(35));
+        assert!(T(Vec::new()).min_score().is_none());
+    }
+
+    #[test]
+    fn test_mean_score() {
+        assert_eq!(T(vec![45, 35, 43, 50]).mean_score(), Some(43.25));
+        assert!(T(Vec::new()).mean_score().is_none());
+    }
 }
@@ -8,6 +8,53 @@ pub trait Sequence {
 
     /// Returns an iterator over bases.
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_>;
+
+    /// Returns the base at the given index.
+    ///
+    /// The default implementation scans the bases using [`Self::iter`]. Implementors should
+    /// override this if they provide random access.
+    fn get(&self, i: usize) -> Option<u8> {
+        self.iter().nth(i)
+    }
+
+    /// Collects the bases into a vector.
+    fn to_vec(&self) -> Vec<u8> {
+        self.iter().collect()
+    }
+
+    /// Returns the reverse complement of the bases.
+    ///
+    /// IUPAC nucleotide codes are complemented, preserving case. Any other base (e.g., `=` or
+    /// `.`) is kept as is.
+    fn reverse_complement(&self) -> Vec<u8> {
+        let mut bases: Vec<_> = self.iter().map(complement).collect();
+        bases.reverse();
+        bases
+    }
+}
+
+fn complement(base: u8) -> u8 {
+    let b = match base.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' | b'U' => b'A',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+        _ => return base,
+    };
+
+    if base.is_ascii_lowercase() {
+        b.to_ascii_lowercase()
+    } else {
+        b
+    }
 }
 
 impl<'a> IntoIterator for &'a dyn Sequence {
@@ -31,6 +78,10 @@ impl Sequence for Box<dyn Sequence + '_> {
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
         (**self).iter()
     }
+
+    fn get(&self, i: usize) -> Option<u8> {
+        (**self).get(i)
+    }
 }
 
 #[cfg(test)]
@@ -62,4 +113,38 @@ mod tests {
             [b'N', b'D', b'L', b'S']
         );
     }
+
+    #[test]
+    fn test_get() {
+        use crate::alignment::record_buf::Sequence as SequenceBuf;
+
+        let sequence_buf = SequenceBuf::from(b"NDLS".to_vec());
+        let sequence: Box<dyn Sequence> = Box::new(&sequence_buf);
+
+        assert_eq!(sequence.get(0), Some(b'N'));
+        assert_eq!(sequence.get(3), Some(b'S'));
+        assert!(sequence.get(4).is_none());
+    }
+
+    #[test]
+    fn test_to_vec() {
+        use crate::alignment::record_buf::Sequence as SequenceBuf;
+
+        let sequence_buf = SequenceBuf::from(b"ACGTN".to_vec());
+        let sequence: &dyn Sequence = &&sequence_buf;
+        assert_eq!(sequence.to_vec(), b"ACGTN");
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        use crate::alignment::record_buf::Sequence as SequenceBuf;
+
+        let sequence_buf = SequenceBuf::from(b"AACGTN".to_vec());
+        let sequence: &dyn Sequence = &&sequence_buf;
+        assert_eq!(sequence.reverse_complement(), b"NACGTT");
+
+        let sequence_buf = SequenceBuf::from(b"acgRY=".to_vec());
+        let sequence: &dyn Sequence = &&sequence_buf;
+        assert_eq!(sequence.reverse_complement(), b"=RYcgt");
+    }
 }
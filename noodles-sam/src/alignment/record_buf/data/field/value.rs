@@ -1,14 +1,60 @@
 //! Alignment record data field value buffer.
 
 mod array;
+mod array_ref;
+#[cfg(feature = "serde")]
+mod json;
+mod strand;
+
+use std::{cmp::Ordering, error, fmt, io};
 
 use bstr::BString;
 
-pub use self::array::Array;
-use crate::alignment::record::data::field::Type;
+pub use self::{array::Array, array_ref::ArrayRef, strand::Strand};
+use crate::alignment::record::data::field::{value::array::Subtype, Tag, Type};
+
+/// An error returned when a big-endian integer array fails to decode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// The subtype is not an integer type.
+    InvalidSubtype(Subtype),
+    /// The input length is not a multiple of the element size.
+    InvalidLength {
+        /// The size of an element.
+        element_size: usize,
+        /// The input length.
+        actual: usize,
+    },
+}
+
+impl error::Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSubtype(subtype) => {
+                write!(
+                    f,
+                    "invalid subtype: expected an integer type, got {subtype:?}"
+                )
+            }
+            Self::InvalidLength {
+                element_size,
+                actual,
+            } => write!(
+                f,
+                "invalid length: expected a multiple of {element_size}, got {actual}"
+            ),
+        }
+    }
+}
 
 /// An alignment record data field value buffer.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Values are totally ordered, first by type, then by contents. Numbers are compared by value,
+/// strings lexicographically, and arrays by subtype and then element-wise. Float NaNs compare
+/// equal to each other and greater than all other floats.
+#[derive(Clone, Debug)]
 pub enum Value {
     /// A character (`A`).
     Character(u8),
@@ -35,6 +81,129 @@ pub enum Value {
 }
 
 impl Value {
+    /// Parses a raw SAM data field value of the given type.
+    ///
+    /// Floats that overflow are parsed as infinities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::data::field::Type, record_buf::data::field::Value};
+    /// assert_eq!(Value::from_str_type("13", Type::Int32)?, Value::UInt8(13));
+    /// assert_eq!(Value::from_str_type("1e40", Type::Float)?, Value::Float(f32::INFINITY));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_str_type(s: &str, ty: Type) -> io::Result<Self> {
+        use crate::io::reader::record_buf::data::field::value::parse_value;
+
+        let mut src = s.as_bytes();
+        parse_value(&mut src, ty).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Parses a raw SAM data field array value.
+    ///
+    /// The input is the array subtype followed by comma-separated values, e.g., `i,1,2,3`. This
+    /// is the same as calling [`Self::from_str_type`] with [`Type::Array`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// assert_eq!(Value::parse_array("i,1,2")?, Value::Array(Array::Int32(vec![1, 2])));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn parse_array(s: &str) -> io::Result<Self> {
+        Self::from_str_type(s, Type::Array)
+    }
+
+    /// Parses a raw SAM data field value of the given type, saturating out-of-range floats.
+    ///
+    /// This is the same as [`Self::from_str_type`], except floats (including those in float
+    /// arrays) that overflow are clamped to `f32::MAX` or `f32::MIN`, and subnormal floats are
+    /// flushed to 0.0. All other values are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::data::field::Type, record_buf::data::field::Value};
+    ///
+    /// assert_eq!(
+    ///     Value::from_str_type_saturating("1e40", Type::Float)?,
+    ///     Value::Float(f32::MAX)
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::from_str_type_saturating("8.0", Type::Float)?,
+    ///     Value::Float(8.0)
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_str_type_saturating(s: &str, ty: Type) -> io::Result<Self> {
+        Self::from_str_type(s, ty).map(|value| match value {
+            Self::Float(n) => Self::Float(saturate_f32(n)),
+            Self::Array(Array::Float(mut values)) => {
+                for n in &mut values {
+                    *n = saturate_f32(*n);
+                }
+
+                Self::Array(Array::Float(values))
+            }
+            _ => value,
+        })
+    }
+
+    /// Decodes an integer array from a buffer of big-endian elements.
+    ///
+    /// BAM integer arrays are little-endian. This is for interoperability with external formats
+    /// that serialize arrays as big-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::array::Subtype,
+    ///     record_buf::data::field::{value::Array, Value},
+    /// };
+    ///
+    /// let value = Value::int_array_from_be_bytes(Subtype::UInt16, &[0x00, 0x08, 0x01, 0x00])?;
+    /// assert_eq!(value, Value::Array(Array::UInt16(vec![8, 256])));
+    /// # Ok::<_, noodles_sam::alignment::record_buf::data::field::value::DecodeError>(())
+    /// ```
+    pub fn int_array_from_be_bytes(subtype: Subtype, src: &[u8]) -> Result<Self, DecodeError> {
+        fn decode<const N: usize, T, F>(src: &[u8], f: F) -> Result<Vec<T>, DecodeError>
+        where
+            F: Fn([u8; N]) -> T,
+        {
+            if src.len() % N != 0 {
+                return Err(DecodeError::InvalidLength {
+                    element_size: N,
+                    actual: src.len(),
+                });
+            }
+
+            Ok(src
+                .chunks_exact(N)
+                .map(|chunk| {
+                    let mut buf = [0; N];
+                    buf.copy_from_slice(chunk);
+                    f(buf)
+                })
+                .collect())
+        }
+
+        let array = match subtype {
+            Subtype::Int8 => decode(src, i8::from_be_bytes).map(Array::Int8)?,
+            Subtype::UInt8 => decode(src, u8::from_be_bytes).map(Array::UInt8)?,
+            Subtype::Int16 => decode(src, i16::from_be_bytes).map(Array::Int16)?,
+            Subtype::UInt16 => decode(src, u16::from_be_bytes).map(Array::UInt16)?,
+            Subtype::Int32 => decode(src, i32::from_be_bytes).map(Array::Int32)?,
+            Subtype::UInt32 => decode(src, u32::from_be_bytes).map(Array::UInt32)?,
+            Subtype::Float => return Err(DecodeError::InvalidSubtype(subtype)),
+        };
+
+        Ok(Self::Array(array))
+    }
+
     /// Returns the type of the value.
     ///
     /// # Examples
@@ -59,6 +228,339 @@ impl Value {
         }
     }
 
+    /// Returns whether the value is representable as the given type.
+    ///
+    /// Integers fit any integer type whose range includes the value. Arrays fit [`Type::Array`]
+    /// and, if given, the array subtype, where integer array values may also be narrowed. The
+    /// subtype is only considered for arrays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::data::field::Type, record_buf::data::field::Value};
+    ///
+    /// let value = Value::UInt16(300);
+    /// assert!(!value.fits_type(Type::Int8, None));
+    /// assert!(value.fits_type(Type::Int32, None));
+    /// ```
+    pub fn fits_type(&self, ty: Type, subtype: Option<Subtype>) -> bool {
+        match self {
+            Self::Character(_) => ty == Type::Character,
+            Self::Float(_) => ty == Type::Float,
+            Self::String(_) => ty == Type::String,
+            Self::Hex(_) => ty == Type::Hex,
+            Self::Array(array) => {
+                ty == Type::Array
+                    && subtype.map_or(true, |subtype| array_fits_subtype(array, subtype))
+            }
+            _ => match self.as_int() {
+                Some(n) => int_fits_type(n, ty),
+                None => false,
+            },
+        }
+    }
+
+    /// Returns the number of bytes the field occupies in SAM text.
+    ///
+    /// This is the length of the full `TAG:TYPE:VALUE` field, as written by the SAM writer, e.g.,
+    /// `NM:i:3` is 6 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag, record_buf::data::field::Value,
+    /// };
+    ///
+    /// assert_eq!(Value::from(3).sam_text_len(Tag::EDIT_DISTANCE), 6);
+    /// ```
+    pub fn sam_text_len(&self, tag: Tag) -> usize {
+        // `:TYPE:`
+        const TYPE_LEN: usize = 3;
+
+        let value_len = match self {
+            Self::Character(_) => 1,
+            Self::Float(n) => float_text_len(*n),
+            Self::String(s) | Self::Hex(s) => s.len(),
+            Self::Array(array) => array_text_len(array),
+            _ => self.as_int().map(int_text_len).unwrap_or_default(),
+        };
+
+        tag.as_ref().len() + TYPE_LEN + value_len
+    }
+
+    /// Returns the raw bytes of a string value.
+    ///
+    /// This returns `None` if the value is not a string (`Z`). Hex strings (`H`) also return
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    /// assert_eq!(Value::from("ndls").as_string_bytes(), Some(&b"ndls"[..]));
+    /// assert!(Value::Int32(0).as_string_bytes().is_none());
+    /// ```
+    pub fn as_string_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::String(s) => Some(s.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns a borrowed view of an array value.
+    ///
+    /// This returns `None` if the value is not an array (`B`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{
+    ///     value::{Array, ArrayRef},
+    ///     Value,
+    /// };
+    ///
+    /// let value = Value::Array(Array::UInt16(vec![8, 13]));
+    /// assert_eq!(value.as_array(), Some(ArrayRef::UInt16(&[8, 13])));
+    ///
+    /// assert!(Value::Int32(0).as_array().is_none());
+    /// ```
+    pub fn as_array(&self) -> Option<ArrayRef<'_>> {
+        match self {
+            Self::Array(array) => Some(ArrayRef::from(array)),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes the value occupies in a BAM data field.
+    ///
+    /// This is the length of the type and the encoded value, excluding the tag. Strings and hex
+    /// strings include the NUL terminator, and arrays include the subtype and 4-byte count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// assert_eq!(Value::Int32(8).encoded_len(), 5);
+    /// assert_eq!(Value::from("ndls").encoded_len(), 6);
+    /// assert_eq!(Value::Array(Array::UInt16(vec![8, 13])).encoded_len(), 10);
+    /// ```
+    pub fn encoded_len(&self) -> usize {
+        const TYPE_LEN: usize = 1;
+        const NUL_LEN: usize = 1;
+        const SUBTYPE_LEN: usize = 1;
+        const COUNT_LEN: usize = 4;
+
+        let value_len = match self {
+            Self::Character(_) | Self::Int8(_) | Self::UInt8(_) => 1,
+            Self::Int16(_) | Self::UInt16(_) => 2,
+            Self::Int32(_) | Self::UInt32(_) | Self::Float(_) => 4,
+            Self::String(s) | Self::Hex(s) => s.len() + NUL_LEN,
+            Self::Array(array) => {
+                let len = match array {
+                    Array::Int8(values) => values.len(),
+                    Array::UInt8(values) => values.len(),
+                    Array::Int16(values) => values.len(),
+                    Array::UInt16(values) => values.len(),
+                    Array::Int32(values) => values.len(),
+                    Array::UInt32(values) => values.len(),
+                    Array::Float(values) => values.len(),
+                };
+
+                SUBTYPE_LEN + COUNT_LEN + len * array.subtype().size()
+            }
+        };
+
+        TYPE_LEN + value_len
+    }
+
+    /// Returns the character value as a strand.
+    ///
+    /// This maps the characters `+`, `-`, `.`, and `?` to a [`Strand`]. Other values return
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Strand, Value};
+    /// assert_eq!(Value::Character(b'+').as_strand(), Some(Strand::Forward));
+    /// assert!(Value::Character(b'n').as_strand().is_none());
+    /// ```
+    pub fn as_strand(&self) -> Option<Strand> {
+        match self {
+            Self::Character(b'.') => Some(Strand::None),
+            Self::Character(b'+') => Some(Strand::Forward),
+            Self::Character(b'-') => Some(Strand::Reverse),
+            Self::Character(b'?') => Some(Strand::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Creates a character value representing a boolean flag.
+    ///
+    /// A set flag is encoded as the character `1` (`A:1`), and an unset flag, the character `0`
+    /// (`A:0`). This is not defined by the SAM specification but is a convention for tags that
+    /// are either present or absent. See also [`Self::as_flag`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    /// assert_eq!(Value::flag(true), Value::Character(b'1'));
+    /// assert_eq!(Value::flag(false), Value::Character(b'0'));
+    /// ```
+    pub fn flag(set: bool) -> Self {
+        if set {
+            Self::Character(b'1')
+        } else {
+            Self::Character(b'0')
+        }
+    }
+
+    /// Returns the value as a boolean flag.
+    ///
+    /// This reads the characters `1` and `0` (see [`Self::flag`]) and the integers 1 and 0 as a
+    /// set and unset flag, respectively. Other values return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    /// assert_eq!(Value::Character(b'1').as_flag(), Some(true));
+    /// assert_eq!(Value::UInt8(0).as_flag(), Some(false));
+    /// assert!(Value::Character(b'n').as_flag().is_none());
+    /// ```
+    pub fn as_flag(&self) -> Option<bool> {
+        match self {
+            Self::Character(b'1') => Some(true),
+            Self::Character(b'0') => Some(false),
+            _ => match self.as_int() {
+                Some(1) => Some(true),
+                Some(0) => Some(false),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the value in its canonical form.
+    ///
+    /// Hex strings (`H`) are uppercased and validated. All other values are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a hex string is not an even number of hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    ///
+    /// let value = Value::Hex(b"cafe".into());
+    /// assert_eq!(value.normalized()?, Value::Hex(b"CAFE".into()));
+    ///
+    /// let value = Value::from("cafe");
+    /// assert_eq!(value.normalized()?, value);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn normalized(&self) -> io::Result<Self> {
+        use crate::io::reader::record_buf::data::field::value::parse_value;
+
+        match self {
+            Self::Hex(s) => {
+                let buf = s.to_ascii_uppercase();
+                let mut src = &buf[..];
+                parse_value(&mut src, Type::Hex)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Returns an iterator over chunks of an array value.
+    ///
+    /// Each chunk is an array value of the same subtype with `size` values, except for possibly
+    /// the last chunk, which has the remaining values. This returns `None` if the value is not an
+    /// array.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt8(vec![0, 1, 2]));
+    /// let mut chunks = value.array_chunks(2).unwrap();
+    /// assert_eq!(chunks.next(), Some(Value::Array(Array::UInt8(vec![0, 1]))));
+    /// assert_eq!(chunks.next(), Some(Value::Array(Array::UInt8(vec![2]))));
+    /// assert!(chunks.next().is_none());
+    ///
+    /// assert!(Value::Int32(0).array_chunks(2).is_none());
+    /// ```
+    pub fn array_chunks(&self, size: usize) -> Option<impl Iterator<Item = Value> + '_> {
+        let Self::Array(array) = self else {
+            return None;
+        };
+
+        let chunks: Box<dyn Iterator<Item = Array>> = match array {
+            Array::Int8(values) => Box::new(values.chunks(size).map(|c| Array::Int8(c.to_vec()))),
+            Array::UInt8(values) => Box::new(values.chunks(size).map(|c| Array::UInt8(c.to_vec()))),
+            Array::Int16(values) => Box::new(values.chunks(size).map(|c| Array::Int16(c.to_vec()))),
+            Array::UInt16(values) => {
+                Box::new(values.chunks(size).map(|c| Array::UInt16(c.to_vec())))
+            }
+            Array::Int32(values) => Box::new(values.chunks(size).map(|c| Array::Int32(c.to_vec()))),
+            Array::UInt32(values) => {
+                Box::new(values.chunks(size).map(|c| Array::UInt32(c.to_vec())))
+            }
+            Array::Float(values) => Box::new(values.chunks(size).map(|c| Array::Float(c.to_vec()))),
+        };
+
+        Some(chunks.map(Self::Array))
+    }
+
+    /// Returns the values of an array value as little-endian bytes.
+    ///
+    /// This is the same layout as the array values in a BAM record, without the subtype and
+    /// length prefix. This returns `None` if the value is not an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt16(vec![1]));
+    /// assert_eq!(value.array_as_le_bytes(), Some(vec![0x01, 0x00]));
+    ///
+    /// assert!(Value::Int32(1).array_as_le_bytes().is_none());
+    /// ```
+    pub fn array_as_le_bytes(&self) -> Option<Vec<u8>> {
+        fn to_le_bytes<T, const N: usize>(values: &[T], f: impl Fn(T) -> [u8; N]) -> Vec<u8>
+        where
+            T: Copy,
+        {
+            values.iter().flat_map(|&n| f(n)).collect()
+        }
+
+        let Self::Array(array) = self else {
+            return None;
+        };
+
+        let buf = match array {
+            Array::Int8(values) => to_le_bytes(values, i8::to_le_bytes),
+            Array::UInt8(values) => values.clone(),
+            Array::Int16(values) => to_le_bytes(values, i16::to_le_bytes),
+            Array::UInt16(values) => to_le_bytes(values, u16::to_le_bytes),
+            Array::Int32(values) => to_le_bytes(values, i32::to_le_bytes),
+            Array::UInt32(values) => to_le_bytes(values, u32::to_le_bytes),
+            Array::Float(values) => to_le_bytes(values, f32::to_le_bytes),
+        };
+
+        Some(buf)
+    }
+
     /// Returns the value as a 64-bit integer.
     ///
     /// This is a convenience method that converts any integer to an `i64`, which captures the
@@ -103,6 +605,182 @@ impl Value {
                 | Self::UInt32(_)
         )
     }
+
+    /// Returns the value as a 64-bit float.
+    ///
+    /// This is a convenience method that widens any integer or float to an `f64`. Characters,
+    /// strings, hex strings, and arrays return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    /// assert_eq!(Value::UInt8(8).as_f64(), Some(8.0));
+    /// assert_eq!(Value::Float(0.5).as_f64(), Some(0.5));
+    /// assert_eq!(Value::from("noodles").as_f64(), None);
+    /// ```
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Int8(n) => Some(f64::from(*n)),
+            Self::UInt8(n) => Some(f64::from(*n)),
+            Self::Int16(n) => Some(f64::from(*n)),
+            Self::UInt16(n) => Some(f64::from(*n)),
+            Self::Int32(n) => Some(f64::from(*n)),
+            Self::UInt32(n) => Some(f64::from(*n)),
+            Self::Float(n) => Some(f64::from(*n)),
+            _ => None,
+        }
+    }
+
+    /// Returns the minimum value of an integer array.
+    ///
+    /// Values are widened to an `i64`. This returns `None` if the value is not an integer array or
+    /// if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// let value = Value::Array(Array::Int16(vec![3, -5, 8]));
+    /// assert_eq!(value.array_min_i64(), Some(-5));
+    /// ```
+    pub fn array_min_i64(&self) -> Option<i64> {
+        self.reduce_int_array(i64::min)
+    }
+
+    /// Returns the maximum value of an integer array.
+    ///
+    /// Values are widened to an `i64`. This returns `None` if the value is not an integer array or
+    /// if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// let value = Value::Array(Array::Int16(vec![3, -5, 8]));
+    /// assert_eq!(value.array_max_i64(), Some(8));
+    /// ```
+    pub fn array_max_i64(&self) -> Option<i64> {
+        self.reduce_int_array(i64::max)
+    }
+
+    /// Returns the minimum value of a numeric array.
+    ///
+    /// Values are widened to an `f64`. NaNs are ignored unless all values are NaN. This returns
+    /// `None` if the value is not an array or if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// let value = Value::Array(Array::Float(vec![0.5, -1.5]));
+    /// assert_eq!(value.array_min_f64(), Some(-1.5));
+    /// ```
+    pub fn array_min_f64(&self) -> Option<f64> {
+        self.reduce_array(f64::min)
+    }
+
+    /// Returns the maximum value of a numeric array.
+    ///
+    /// Values are widened to an `f64`. NaNs are ignored unless all values are NaN. This returns
+    /// `None` if the value is not an array or if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    /// let value = Value::Array(Array::Float(vec![0.5, -1.5]));
+    /// assert_eq!(value.array_max_f64(), Some(0.5));
+    /// ```
+    pub fn array_max_f64(&self) -> Option<f64> {
+        self.reduce_array(f64::max)
+    }
+
+    fn reduce_int_array<F>(&self, f: F) -> Option<i64>
+    where
+        F: Fn(i64, i64) -> i64,
+    {
+        fn reduce<T, F>(values: &[T], f: F) -> Option<i64>
+        where
+            T: Copy + Into<i64>,
+            F: Fn(i64, i64) -> i64,
+        {
+            values.iter().map(|&n| n.into()).reduce(f)
+        }
+
+        match self {
+            Self::Array(Array::Int8(values)) => reduce(values, f),
+            Self::Array(Array::UInt8(values)) => reduce(values, f),
+            Self::Array(Array::Int16(values)) => reduce(values, f),
+            Self::Array(Array::UInt16(values)) => reduce(values, f),
+            Self::Array(Array::Int32(values)) => reduce(values, f),
+            Self::Array(Array::UInt32(values)) => reduce(values, f),
+            _ => None,
+        }
+    }
+
+    fn reduce_array<F>(&self, f: F) -> Option<f64>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        fn reduce<T, F>(values: &[T], f: F) -> Option<f64>
+        where
+            T: Copy + Into<f64>,
+            F: Fn(f64, f64) -> f64,
+        {
+            values.iter().map(|&n| n.into()).reduce(f)
+        }
+
+        match self {
+            Self::Array(Array::Int8(values)) => reduce(values, f),
+            Self::Array(Array::UInt8(values)) => reduce(values, f),
+            Self::Array(Array::Int16(values)) => reduce(values, f),
+            Self::Array(Array::UInt16(values)) => reduce(values, f),
+            Self::Array(Array::Int32(values)) => reduce(values, f),
+            Self::Array(Array::UInt32(values)) => reduce(values, f),
+            Self::Array(Array::Float(values)) => reduce(values, f),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::alignment::record::data::field::Value::from(self).fmt(f)
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Character(a), Self::Character(b)) => a.cmp(b),
+            (Self::Int8(a), Self::Int8(b)) => a.cmp(b),
+            (Self::UInt8(a), Self::UInt8(b)) => a.cmp(b),
+            (Self::Int16(a), Self::Int16(b)) => a.cmp(b),
+            (Self::UInt16(a), Self::UInt16(b)) => a.cmp(b),
+            (Self::Int32(a), Self::Int32(b)) => a.cmp(b),
+            (Self::UInt32(a), Self::UInt32(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => cmp_floats(*a, *b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::Hex(a), Self::Hex(b)) => a.cmp(b),
+            (Self::Array(a), Self::Array(b)) => a.cmp(b),
+            _ => self.ty().cmp(&other.ty()),
+        }
+    }
 }
 
 impl From<i8> for Value {
@@ -229,6 +907,48 @@ impl From<Vec<f32>> for Value {
     }
 }
 
+impl From<&[i8]> for Value {
+    fn from(values: &[i8]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(values: &[u8]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[i16]> for Value {
+    fn from(values: &[i16]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[u16]> for Value {
+    fn from(values: &[u16]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[i32]> for Value {
+    fn from(values: &[i32]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[u32]> for Value {
+    fn from(values: &[u32]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
+impl From<&[f32]> for Value {
+    fn from(values: &[f32]) -> Self {
+        Self::from(values.to_vec())
+    }
+}
+
 impl TryFrom<i64> for Value {
     type Error = crate::io::reader::record_buf::data::field::value::ParseError;
 
@@ -270,10 +990,547 @@ impl<'a> From<&'a Value> for crate::alignment::record::data::field::Value<'a> {
     }
 }
 
+fn int_fits_type(n: i64, ty: Type) -> bool {
+    match ty {
+        Type::Int8 => i8::try_from(n).is_ok(),
+        Type::UInt8 => u8::try_from(n).is_ok(),
+        Type::Int16 => i16::try_from(n).is_ok(),
+        Type::UInt16 => u16::try_from(n).is_ok(),
+        Type::Int32 => i32::try_from(n).is_ok(),
+        Type::UInt32 => u32::try_from(n).is_ok(),
+        _ => false,
+    }
+}
+
+fn array_fits_subtype(array: &Array, subtype: Subtype) -> bool {
+    fn all_fit<T>(values: &[T], ty: Type) -> bool
+    where
+        T: Copy + Into<i64>,
+    {
+        values.iter().all(|&n| int_fits_type(n.into(), ty))
+    }
+
+    let ty = match subtype {
+        Subtype::Int8 => Type::Int8,
+        Subtype::UInt8 => Type::UInt8,
+        Subtype::Int16 => Type::Int16,
+        Subtype::UInt16 => Type::UInt16,
+        Subtype::Int32 => Type::Int32,
+        Subtype::UInt32 => Type::UInt32,
+        Subtype::Float => return matches!(array, Array::Float(_)),
+    };
+
+    match array {
+        Array::Int8(values) => all_fit(values, ty),
+        Array::UInt8(values) => all_fit(values, ty),
+        Array::Int16(values) => all_fit(values, ty),
+        Array::UInt16(values) => all_fit(values, ty),
+        Array::Int32(values) => all_fit(values, ty),
+        Array::UInt32(values) => all_fit(values, ty),
+        Array::Float(_) => false,
+    }
+}
+
+fn int_text_len(n: i64) -> usize {
+    let sign_len = usize::from(n < 0);
+    let digit_count = n
+        .unsigned_abs()
+        .checked_ilog10()
+        .map(|d| d as usize + 1)
+        .unwrap_or(1);
+
+    sign_len + digit_count
+}
+
+fn float_text_len(n: f32) -> usize {
+    use crate::io::writer::num;
+
+    let mut buf = Vec::new();
+    num::write_f32(&mut buf, n).ok();
+    buf.len()
+}
+
+fn array_text_len(array: &Array) -> usize {
+    fn sum<T>(values: &[T], f: impl Fn(&T) -> usize) -> usize {
+        // `,` + value
+        values.iter().map(|n| 1 + f(n)).sum()
+    }
+
+    // subtype
+    let subtype_len = 1;
+
+    let values_len = match array {
+        Array::Int8(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::UInt8(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::Int16(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::UInt16(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::Int32(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::UInt32(values) => sum(values, |&n| int_text_len(n.into())),
+        Array::Float(values) => sum(values, |n| n.to_string().len()),
+    };
+
+    subtype_len + values_len
+}
+
+// NaNs compare equal to each other and greater than all other values.
+fn cmp_floats(a: f32, b: f32) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+fn saturate_f32(n: f32) -> f32 {
+    if n == f32::INFINITY {
+        f32::MAX
+    } else if n == f32::NEG_INFINITY {
+        f32::MIN
+    } else if n.is_subnormal() {
+        0.0
+    } else {
+        n
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_array_min_max() {
+        let value = Value::Array(Array::Int16(vec![3, -5, 8, -2]));
+        assert_eq!(value.array_min_i64(), Some(-5));
+        assert_eq!(value.array_max_i64(), Some(8));
+        assert_eq!(value.array_min_f64(), Some(-5.0));
+        assert_eq!(value.array_max_f64(), Some(8.0));
+
+        let value = Value::Array(Array::UInt32(vec![u32::MAX, 0]));
+        assert_eq!(value.array_max_i64(), Some(i64::from(u32::MAX)));
+
+        let value = Value::Array(Array::Int16(Vec::new()));
+        assert!(value.array_min_i64().is_none());
+        assert!(value.array_max_i64().is_none());
+        assert!(value.array_min_f64().is_none());
+        assert!(value.array_max_f64().is_none());
+
+        let value = Value::Array(Array::Float(vec![0.5, f32::NAN, -1.5]));
+        assert!(value.array_min_i64().is_none());
+        assert_eq!(value.array_min_f64(), Some(-1.5));
+        assert_eq!(value.array_max_f64(), Some(0.5));
+
+        assert!(Value::Int8(1).array_min_i64().is_none());
+        assert!(Value::Int8(1).array_max_f64().is_none());
+    }
+
+    #[test]
+    fn test_normalized() -> io::Result<()> {
+        assert_eq!(
+            Value::Hex(BString::from("cafe")).normalized()?,
+            Value::Hex(BString::from("CAFE"))
+        );
+        assert_eq!(
+            Value::Hex(BString::from("CaFe01")).normalized()?,
+            Value::Hex(BString::from("CAFE01"))
+        );
+
+        assert!(matches!(
+            Value::Hex(BString::from("caf")).normalized(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            Value::Hex(BString::from("zz")).normalized(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(Value::from("cafe").normalized()?, Value::from("cafe"));
+        assert_eq!(Value::UInt8(8).normalized()?, Value::UInt8(8));
+        assert_eq!(
+            Value::from(vec![1u8, 2]).normalized()?,
+            Value::from(vec![1u8, 2])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_chunks() {
+        let value = Value::Array(Array::UInt8(vec![0, 1, 2, 3, 4]));
+        let chunks: Vec<_> = value.array_chunks(2).unwrap().collect();
+
+        assert_eq!(
+            chunks,
+            [
+                Value::Array(Array::UInt8(vec![0, 1])),
+                Value::Array(Array::UInt8(vec![2, 3])),
+                Value::Array(Array::UInt8(vec![4])),
+            ]
+        );
+
+        let value = Value::Array(Array::Float(Vec::new()));
+        assert_eq!(value.array_chunks(2).unwrap().count(), 0);
+
+        assert!(Value::from("ndls").array_chunks(2).is_none());
+    }
+
+    #[test]
+    fn test_array_as_le_bytes() {
+        fn t(array: Array, expected: &[u8]) {
+            assert_eq!(
+                Value::Array(array).array_as_le_bytes(),
+                Some(expected.to_vec())
+            );
+        }
+
+        t(Array::Int8(vec![-1]), &[0xff]);
+        t(Array::UInt8(vec![1, 2]), &[0x01, 0x02]);
+        t(Array::Int16(vec![-2]), &[0xfe, 0xff]);
+        t(Array::UInt16(vec![1]), &[0x01, 0x00]);
+        t(Array::Int32(vec![1]), &[0x01, 0x00, 0x00, 0x00]);
+        t(Array::UInt32(vec![256]), &[0x00, 0x01, 0x00, 0x00]);
+        t(Array::Float(vec![1.0]), &[0x00, 0x00, 0x80, 0x3f]);
+
+        assert!(Value::from("ndls").array_as_le_bytes().is_none());
+    }
+
+    #[test]
+    fn test_as_strand() {
+        assert_eq!(Value::Character(b'.').as_strand(), Some(Strand::None));
+        assert_eq!(Value::Character(b'+').as_strand(), Some(Strand::Forward));
+        assert_eq!(Value::Character(b'-').as_strand(), Some(Strand::Reverse));
+        assert_eq!(Value::Character(b'?').as_strand(), Some(Strand::Unknown));
+
+        assert!(Value::Character(b'n').as_strand().is_none());
+        assert!(Value::from("+").as_strand().is_none());
+    }
+
+    #[test]
+    fn test_flag() {
+        assert_eq!(Value::flag(true).as_flag(), Some(true));
+        assert_eq!(Value::flag(false).as_flag(), Some(false));
+
+        assert_eq!(Value::Int32(1).as_flag(), Some(true));
+        assert_eq!(Value::UInt8(0).as_flag(), Some(false));
+
+        assert!(Value::Int8(2).as_flag().is_none());
+        assert!(Value::Character(b'Y').as_flag().is_none());
+        assert!(Value::from("1").as_flag().is_none());
+    }
+
+    #[test]
+    fn test_as_string_bytes() {
+        assert_eq!(
+            Value::from("noodles").as_string_bytes(),
+            Some(&b"noodles"[..])
+        );
+        assert_eq!(Value::from("").as_string_bytes(), Some(&b""[..]));
+
+        assert!(Value::Character(b'n').as_string_bytes().is_none());
+        assert!(Value::Hex(b"CAFE".into()).as_string_bytes().is_none());
+        assert!(Value::Array(Array::UInt8(vec![0]))
+            .as_string_bytes()
+            .is_none());
+    }
+
+    #[test]
+    fn test_as_array() {
+        fn sum(array: ArrayRef<'_>) -> f64 {
+            match array {
+                ArrayRef::Int8(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::UInt8(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::Int16(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::UInt16(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::Int32(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::UInt32(values) => values.iter().copied().map(f64::from).sum(),
+                ArrayRef::Float(values) => values.iter().copied().map(f64::from).sum(),
+            }
+        }
+
+        let value = Value::Array(Array::Int8(vec![1, -2]));
+        assert_eq!(value.as_array(), Some(ArrayRef::Int8(&[1, -2])));
+
+        let value = Value::Array(Array::UInt8(vec![3]));
+        assert_eq!(value.as_array(), Some(ArrayRef::UInt8(&[3])));
+
+        let value = Value::Array(Array::Int16(vec![-5, 8]));
+        assert_eq!(value.as_array(), Some(ArrayRef::Int16(&[-5, 8])));
+
+        let value = Value::Array(Array::UInt16(vec![13]));
+        assert_eq!(value.as_array(), Some(ArrayRef::UInt16(&[13])));
+
+        let value = Value::Array(Array::Int32(vec![-21]));
+        assert_eq!(value.as_array(), Some(ArrayRef::Int32(&[-21])));
+
+        let value = Value::Array(Array::UInt32(vec![34, 55]));
+        assert_eq!(value.as_array(), Some(ArrayRef::UInt32(&[34, 55])));
+
+        let value = Value::Array(Array::Float(vec![0.5, 0.25]));
+        assert_eq!(value.as_array(), Some(ArrayRef::Float(&[0.5, 0.25])));
+
+        let value = Value::Array(Array::UInt32(vec![34, 55]));
+        assert_eq!(value.as_array().map(sum), Some(89.0));
+
+        assert!(Value::Int32(0).as_array().is_none());
+        assert!(Value::from("ndls").as_array().is_none());
+    }
+
+    #[test]
+    fn test_encoded_len() {
+        assert_eq!(Value::Character(b'n').encoded_len(), 2);
+        assert_eq!(Value::Int8(-1).encoded_len(), 2);
+        assert_eq!(Value::UInt16(8).encoded_len(), 3);
+        assert_eq!(Value::Float(0.0).encoded_len(), 5);
+
+        assert_eq!(Value::from("").encoded_len(), 2);
+        assert_eq!(Value::from("noodles").encoded_len(), 9);
+        assert_eq!(Value::Hex(b"CAFE".into()).encoded_len(), 6);
+
+        assert_eq!(Value::Array(Array::UInt8(Vec::new())).encoded_len(), 6);
+        assert_eq!(
+            Value::Array(Array::Int32(vec![8, 13, 21])).encoded_len(),
+            18
+        );
+    }
+
+    #[test]
+    fn test_sam_text_len() {
+        assert_eq!(
+            Value::Int32(3).sam_text_len(Tag::EDIT_DISTANCE),
+            b"NM:i:3".len()
+        );
+        assert_eq!(
+            Value::Int8(-13).sam_text_len(Tag::EDIT_DISTANCE),
+            b"NM:i:-13".len()
+        );
+        assert_eq!(
+            Value::UInt32(0).sam_text_len(Tag::EDIT_DISTANCE),
+            b"NM:i:0".len()
+        );
+        assert_eq!(
+            Value::Character(b'n').sam_text_len(Tag::COMMENT),
+            b"CO:A:n".len()
+        );
+        assert_eq!(
+            Value::Float(0.5).sam_text_len(Tag::COMMENT),
+            b"CO:f:0.5".len()
+        );
+        assert_eq!(
+            Value::from("ndls").sam_text_len(Tag::COMMENT),
+            b"CO:Z:ndls".len()
+        );
+        assert_eq!(
+            Value::Hex(BString::from("CAFE")).sam_text_len(Tag::COMMENT),
+            b"CO:H:CAFE".len()
+        );
+        assert_eq!(
+            Value::Array(Array::Int16(vec![8, -13])).sam_text_len(Tag::COMMENT),
+            b"CO:B:s,8,-13".len()
+        );
+        assert_eq!(
+            Value::Array(Array::Float(vec![8.0, 0.5])).sam_text_len(Tag::COMMENT),
+            b"CO:B:f,8,0.5".len()
+        );
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Value::Character(b'n').to_string(), "n");
+        assert_eq!(Value::UInt8(13).to_string(), "13");
+        assert_eq!(Value::Float(8.5).to_string(), "8.5");
+        assert_eq!(Value::from("ndls").to_string(), "ndls");
+        assert_eq!(Value::Hex(b"CAFE".into()).to_string(), "CAFE");
+        assert_eq!(Value::from(vec![1i32, -2]).to_string(), "i,1,-2");
+        assert_eq!(Value::from(vec![0.5f32]).to_string(), "f,0.5");
+
+        assert_eq!(Value::Character(b'\n').to_string(), "\n");
+        assert_eq!(Value::Float(f32::NAN).to_string(), "NaN");
+        assert_eq!(Value::Float(f32::INFINITY).to_string(), "inf");
+        assert_eq!(Value::from(vec![f32::NEG_INFINITY]).to_string(), "f,-inf");
+    }
+
+    #[test]
+    fn test_cmp() {
+        // type
+        assert!(Value::Character(b'z') < Value::Int8(0));
+        assert!(Value::UInt32(u32::MAX) < Value::Float(0.0));
+        assert!(Value::from("a") < Value::Hex(BString::from("00")));
+        assert!(Value::Float(f32::NAN) < Value::Array(Array::Int8(Vec::new())));
+
+        // contents
+        assert!(Value::Int32(-1) < Value::Int32(0));
+        assert!(Value::Float(0.5) < Value::Float(1.5));
+        assert!(Value::Float(f32::INFINITY) < Value::Float(f32::NAN));
+        assert_eq!(Value::Float(f32::NAN), Value::Float(f32::NAN));
+        assert!(Value::from("ab") < Value::from("b"));
+
+        let mut values = vec![
+            Value::from("noodles"),
+            Value::Int8(8),
+            Value::Character(b'n'),
+            Value::Int8(-8),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                Value::Character(b'n'),
+                Value::Int8(-8),
+                Value::Int8(8),
+                Value::from("noodles"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_f64() {
+        assert_eq!(Value::Int8(-8).as_f64(), Some(-8.0));
+        assert_eq!(Value::UInt32(u32::MAX).as_f64(), Some(f64::from(u32::MAX)));
+        assert_eq!(Value::Float(0.5).as_f64(), Some(0.5));
+        assert_eq!(Value::Character(b'n').as_f64(), None);
+        assert_eq!(Value::from("noodles").as_f64(), None);
+        assert_eq!(Value::Array(Array::UInt8(vec![0])).as_f64(), None);
+    }
+
+    #[test]
+    fn test_parse_array() -> io::Result<()> {
+        assert_eq!(
+            Value::parse_array("c,-1,2")?,
+            Value::Array(Array::Int8(vec![-1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("C,1,2")?,
+            Value::Array(Array::UInt8(vec![1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("s,-1,2")?,
+            Value::Array(Array::Int16(vec![-1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("S,1,2")?,
+            Value::Array(Array::UInt16(vec![1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("i,-1,2")?,
+            Value::Array(Array::Int32(vec![-1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("I,1,2")?,
+            Value::Array(Array::UInt32(vec![1, 2]))
+        );
+        assert_eq!(
+            Value::parse_array("f,0.5,1")?,
+            Value::Array(Array::Float(vec![0.5, 1.0]))
+        );
+
+        assert!(matches!(
+            Value::parse_array(""),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(
+            Value::parse_array("n,1,2"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_type_saturating() -> io::Result<()> {
+        assert_eq!(
+            Value::from_str_type_saturating("1e40", Type::Float)?,
+            Value::Float(f32::MAX)
+        );
+        assert_eq!(
+            Value::from_str_type_saturating("-1e40", Type::Float)?,
+            Value::Float(f32::MIN)
+        );
+        assert_eq!(
+            Value::from_str_type_saturating("1e-40", Type::Float)?,
+            Value::Float(0.0)
+        );
+        assert_eq!(
+            Value::from_str_type_saturating("0.5", Type::Float)?,
+            Value::Float(0.5)
+        );
+        assert_eq!(
+            Value::from_str_type_saturating("f,1e40,0.5", Type::Array)?,
+            Value::Array(Array::Float(vec![f32::MAX, 0.5]))
+        );
+        assert_eq!(
+            Value::from_str_type_saturating("13", Type::Int32)?,
+            Value::UInt8(13)
+        );
+
+        assert_eq!(
+            Value::from_str_type("1e40", Type::Float)?,
+            Value::Float(f32::INFINITY)
+        );
+
+        assert!(matches!(
+            Value::from_str_type_saturating("ndls", Type::Float),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_array_from_be_bytes() {
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::UInt16, &[0x00, 0x08, 0x01, 0x00]),
+            Ok(Value::Array(Array::UInt16(vec![8, 256])))
+        );
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::Int8, &[0xff, 0x08]),
+            Ok(Value::Array(Array::Int8(vec![-1, 8])))
+        );
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::Int32, &[0xff, 0xff, 0xff, 0xfe]),
+            Ok(Value::Array(Array::Int32(vec![-2])))
+        );
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::UInt32, &[]),
+            Ok(Value::Array(Array::UInt32(Vec::new())))
+        );
+
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::UInt16, &[0x00, 0x08, 0x01]),
+            Err(DecodeError::InvalidLength {
+                element_size: 2,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            Value::int_array_from_be_bytes(Subtype::Float, &[0x00, 0x00, 0x00, 0x00]),
+            Err(DecodeError::InvalidSubtype(Subtype::Float))
+        );
+    }
+
+    #[test]
+    fn test_fits_type() {
+        assert!(!Value::UInt16(300).fits_type(Type::Int8, None));
+        assert!(!Value::UInt16(300).fits_type(Type::UInt8, None));
+        assert!(Value::UInt16(300).fits_type(Type::Int16, None));
+        assert!(Value::UInt16(300).fits_type(Type::Int32, None));
+        assert!(!Value::Int8(-1).fits_type(Type::UInt32, None));
+        assert!(!Value::UInt8(0).fits_type(Type::Float, None));
+
+        assert!(Value::Character(b'n').fits_type(Type::Character, None));
+        assert!(Value::Float(0.0).fits_type(Type::Float, None));
+        assert!(!Value::Float(0.0).fits_type(Type::Int32, None));
+        assert!(Value::from("ndls").fits_type(Type::String, None));
+        assert!(!Value::from("ndls").fits_type(Type::Hex, None));
+        assert!(Value::Hex(b"CAFE".into()).fits_type(Type::Hex, None));
+
+        let value = Value::Array(Array::UInt16(vec![8, 300]));
+        assert!(value.fits_type(Type::Array, None));
+        assert!(value.fits_type(Type::Array, Some(Subtype::Int16)));
+        assert!(!value.fits_type(Type::Array, Some(Subtype::UInt8)));
+        assert!(!value.fits_type(Type::Array, Some(Subtype::Float)));
+        assert!(!value.fits_type(Type::UInt16, None));
+
+        let value = Value::Array(Array::Float(vec![0.0]));
+        assert!(value.fits_type(Type::Array, Some(Subtype::Float)));
+        assert!(!value.fits_type(Type::Array, Some(Subtype::Int32)));
+    }
+
     #[test]
     fn test_ty() {
         assert_eq!(Value::Character(b'n').ty(), Type::Character);
@@ -408,6 +1665,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_slice_for_value() {
+        assert_eq!(Value::from(&[0i8][..]), Value::Array(Array::Int8(vec![0])));
+        assert_eq!(Value::from(&[0u8][..]), Value::Array(Array::UInt8(vec![0])));
+        assert_eq!(
+            Value::from(&[0i16][..]),
+            Value::Array(Array::Int16(vec![0]))
+        );
+        assert_eq!(
+            Value::from(&[0u16][..]),
+            Value::Array(Array::UInt16(vec![0]))
+        );
+        assert_eq!(
+            Value::from(&[0i32][..]),
+            Value::Array(Array::Int32(vec![0]))
+        );
+        assert_eq!(
+            Value::from(&[0u32][..]),
+            Value::Array(Array::UInt32(vec![0]))
+        );
+        assert_eq!(
+            Value::from(&[0.0f32][..]),
+            Value::Array(Array::Float(vec![0.0]))
+        );
+    }
+
     #[test]
     fn test_try_from_i64_for_value(
     ) -> Result<(), crate::io::reader::record_buf::data::field::value::ParseError> {
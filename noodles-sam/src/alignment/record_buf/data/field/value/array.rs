@@ -1,11 +1,13 @@
 //! Alignment record data field array value buffer.
 
-use std::io;
+use std::{cmp::Ordering, io};
 
 use crate::alignment::record::data::field::value::array::Subtype;
 
 /// An alignment record data field array value buffer.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Arrays are ordered by subtype and then element-wise.
+#[derive(Clone, Debug)]
 pub enum Array {
     /// An 8-bit integer array (`B:c`).
     Int8(Vec<i8>),
@@ -48,6 +50,40 @@ impl Array {
     }
 }
 
+impl PartialEq for Array {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Array {}
+
+impl PartialOrd for Array {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Array {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int8(a), Self::Int8(b)) => a.cmp(b),
+            (Self::UInt8(a), Self::UInt8(b)) => a.cmp(b),
+            (Self::Int16(a), Self::Int16(b)) => a.cmp(b),
+            (Self::UInt16(a), Self::UInt16(b)) => a.cmp(b),
+            (Self::Int32(a), Self::Int32(b)) => a.cmp(b),
+            (Self::UInt32(a), Self::UInt32(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| super::cmp_floats(*x, *y))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            _ => self.subtype().cmp(&other.subtype()),
+        }
+    }
+}
+
 impl<'a> From<&'a Array> for crate::alignment::record::data::field::value::Array<'a> {
     fn from(array_buf: &'a Array) -> Self {
         match array_buf {
@@ -81,6 +117,10 @@ where
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<N>> + '_> {
         Box::new(self.0.iter().copied().map(Ok))
     }
+
+    fn get(&self, i: usize) -> Option<io::Result<N>> {
+        self.0.get(i).copied().map(Ok)
+    }
 }
 
 #[cfg(test)]
@@ -97,4 +137,18 @@ mod tests {
         assert_eq!(Array::UInt32(Vec::new()).subtype(), Subtype::UInt32);
         assert_eq!(Array::Float(Vec::new()).subtype(), Subtype::Float);
     }
+
+    #[test]
+    fn test_cmp() {
+        assert!(Array::Int8(vec![1]) < Array::UInt8(vec![0]));
+        assert!(Array::UInt32(vec![0]) < Array::Float(Vec::new()));
+
+        assert!(Array::Int16(vec![1, 2]) < Array::Int16(vec![1, 3]));
+        assert!(Array::Int16(vec![1, 2]) < Array::Int16(vec![1, 2, 0]));
+        assert!(Array::Int16(vec![2]) > Array::Int16(vec![1, 2]));
+
+        assert!(Array::Float(vec![0.0, 1.0]) < Array::Float(vec![0.0, f32::NAN]));
+        assert!(Array::Float(vec![0.0]) < Array::Float(vec![0.0, 0.0]));
+        assert_eq!(Array::Float(vec![f32::NAN]), Array::Float(vec![f32::NAN]));
+    }
 }
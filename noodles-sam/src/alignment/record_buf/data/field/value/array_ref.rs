@@ -0,0 +1,37 @@
+use super::Array;
+
+/// A borrowed alignment record data field array value buffer.
+///
+/// This is a view of an array value with one variant per subtype (see
+/// [`super::Value::as_array`]).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrayRef<'a> {
+    /// An 8-bit integer array (`B:c`).
+    Int8(&'a [i8]),
+    /// An 8-bit unsigned integer array (`B:C`).
+    UInt8(&'a [u8]),
+    /// A 16-bit integer array (`B:s`).
+    Int16(&'a [i16]),
+    /// A 16-bit unsigned integer array (`B:S`).
+    UInt16(&'a [u16]),
+    /// A 32-bit integer array (`B:i`).
+    Int32(&'a [i32]),
+    /// A 32-bit unsigned integer array (`B:I`).
+    UInt32(&'a [u32]),
+    /// A single-precision floating-point array (`B:f`).
+    Float(&'a [f32]),
+}
+
+impl<'a> From<&'a Array> for ArrayRef<'a> {
+    fn from(array: &'a Array) -> Self {
+        match array {
+            Array::Int8(values) => Self::Int8(values),
+            Array::UInt8(values) => Self::UInt8(values),
+            Array::Int16(values) => Self::Int16(values),
+            Array::UInt16(values) => Self::UInt16(values),
+            Array::Int32(values) => Self::Int32(values),
+            Array::UInt32(values) => Self::UInt32(values),
+            Array::Float(values) => Self::Float(values),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use super::{Array, Value};
+
+/// Converts a data field value to a JSON value.
+///
+/// The value is mapped as follows:
+///
+///   * integers (`c`, `C`, `s`, `S`, `i`, `I`) and floats (`f`) are numbers. Non-finite floats,
+///     which are not representable in JSON, are `null`;
+///   * a character (`A`) is a string of that single character;
+///   * a string (`Z`) is a string. Invalid UTF-8 sequences are replaced with U+FFFD;
+///   * a hex string (`H`) is the string of hex digits as is, i.e., it is not decoded; and
+///   * an array (`B`) is an array of numbers. The subtype is not retained.
+impl From<&Value> for serde_json::Value {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Character(c) => Self::String(char::from(*c).to_string()),
+            Value::Int8(n) => Self::from(*n),
+            Value::UInt8(n) => Self::from(*n),
+            Value::Int16(n) => Self::from(*n),
+            Value::UInt16(n) => Self::from(*n),
+            Value::Int32(n) => Self::from(*n),
+            Value::UInt32(n) => Self::from(*n),
+            Value::Float(n) => float_to_json(*n),
+            Value::String(s) | Value::Hex(s) => Self::String(s.to_string()),
+            Value::Array(array) => array_to_json(array),
+        }
+    }
+}
+
+fn float_to_json(n: f32) -> serde_json::Value {
+    serde_json::Number::from_f64(f64::from(n))
+        .map(serde_json::Value::Number)
+        .unwrap_or_default()
+}
+
+fn array_to_json(array: &Array) -> serde_json::Value {
+    fn collect<T>(values: &[T]) -> serde_json::Value
+    where
+        T: Copy + Into<serde_json::Value>,
+    {
+        serde_json::Value::Array(values.iter().map(|&n| n.into()).collect())
+    }
+
+    match array {
+        Array::Int8(values) => collect(values),
+        Array::UInt8(values) => collect(values),
+        Array::Int16(values) => collect(values),
+        Array::UInt16(values) => collect(values),
+        Array::Int32(values) => collect(values),
+        Array::UInt32(values) => collect(values),
+        Array::Float(values) => {
+            serde_json::Value::Array(values.iter().copied().map(float_to_json).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_from_value_for_serde_json_value() {
+        fn t(value: Value, expected: serde_json::Value) {
+            assert_eq!(serde_json::Value::from(&value), expected);
+        }
+
+        t(Value::Character(b'n'), json!("n"));
+        t(Value::Int8(-8), json!(-8));
+        t(Value::UInt32(13), json!(13));
+        t(Value::Float(0.5), json!(0.5));
+        t(Value::Float(f32::NAN), json!(null));
+        t(Value::from("noodles"), json!("noodles"));
+        t(Value::Hex(b"CAFE".into()), json!("CAFE"));
+        t(Value::Array(Array::Int16(vec![1, -2])), json!([1, -2]));
+        t(Value::Array(Array::Float(vec![0.5])), json!([0.5]));
+    }
+}
@@ -0,0 +1,14 @@
+/// An alignment record data field strand.
+///
+/// This is the strand interpretation of a character (`A`) value (see [`super::Value::as_strand`]).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Strand {
+    /// Unstranded (`.`).
+    None,
+    /// Forward strand (`+`).
+    Forward,
+    /// Reverse strand (`-`).
+    Reverse,
+    /// Strandedness is relevant but unknown (`?`).
+    Unknown,
+}
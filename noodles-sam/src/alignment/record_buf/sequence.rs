@@ -86,4 +86,8 @@ impl crate::alignment::record::Sequence for &Sequence {
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
         Box::new(self.0.iter().copied())
     }
+
+    fn get(&self, i: usize) -> Option<u8> {
+        self.0.get(i).copied()
+    }
 }
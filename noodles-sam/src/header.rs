@@ -77,7 +77,7 @@ pub mod record;
 
 pub use self::{
     builder::Builder,
-    parser::{ParseError, Parser},
+    parser::{HeaderFieldError, ParseError, Parser},
     record::Record,
 };
 
@@ -165,6 +165,34 @@ impl Header {
         &mut self.header
     }
 
+    /// Returns the sort order (`@HD SO`).
+    ///
+    /// This returns `None` if there is no header (`@HD`) record or if the sort order is not set.
+    /// A sort order that is not one of the standard values is treated as
+    /// [`SortOrder::Unknown`](record::value::map::header::sort_order::SortOrder::Unknown).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::sort_order::SortOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert!(header.sort_order().is_none());
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+    /// assert_eq!(header.sort_order(), Some(SortOrder::Coordinate));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn sort_order(&self) -> Option<record::value::map::header::sort_order::SortOrder> {
+        use record::value::map::header::{sort_order::SortOrder, tag};
+
+        self.header()
+            .and_then(|hd| hd.other_fields().get(&tag::SORT_ORDER))
+            .map(|sort_order| {
+                SortOrder::try_from(sort_order.as_ref()).unwrap_or(SortOrder::Unknown)
+            })
+    }
+
     /// Returns the SAM header reference sequences.
     ///
     /// This is also called the reference sequence dictionary.
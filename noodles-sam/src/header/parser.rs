@@ -7,7 +7,8 @@ use bstr::BString;
 use indexmap::IndexMap;
 
 pub(crate) use self::context::Context;
-use self::record::parse_record;
+pub use self::record::value::map::header::ParseError as HeaderFieldError;
+use self::record::{parse_record, parse_record_lenient};
 use super::{
     record::value::{
         map::{self, header::Version},
@@ -66,6 +67,7 @@ pub struct Parser {
     read_groups: ReadGroups,
     programs: Programs,
     comments: Vec<BString>,
+    skipped_header_tags: Vec<map::header::Tag>,
 }
 
 impl Parser {
@@ -77,6 +79,65 @@ impl Parser {
             && self.comments.is_empty()
     }
 
+    /// Sets whether to skip header (`@HD`) fields with invalid values.
+    ///
+    /// By default, a header field with an invalid value is an error. When set, the field is
+    /// skipped instead, and its tag is recorded (see [`Self::skipped_header_tags`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::map::header::{tag, Tag},
+    /// };
+    ///
+    /// let mut parser = sam::header::Parser::default();
+    /// assert!(parser.parse_partial(b"@HD\tVN:1.6\tSO:").is_err());
+    ///
+    /// let mut parser = sam::header::Parser::default();
+    /// parser.set_skip_invalid_fields(true);
+    /// parser.parse_partial(b"@HD\tVN:1.6\tSO:")?;
+    /// assert_eq!(parser.skipped_header_tags(), [Tag::Other(tag::SORT_ORDER)]);
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn set_skip_invalid_fields(&mut self, skip_invalid_fields: bool) {
+        self.ctx.set_skip_invalid_fields(skip_invalid_fields);
+    }
+
+    /// Sets whether nonstandard field values and comments must be valid UTF-8.
+    ///
+    /// By default, nonstandard field values and comments (`@CO`) are preserved as raw bytes, e.g.,
+    /// Latin-1 encoded text. When enabled, a value that is not valid UTF-8 is an invalid field,
+    /// and a comment that is not valid UTF-8 is an invalid record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let src = b"@HD\tVN:1.6\tzn:\x80";
+    ///
+    /// let mut parser = sam::header::Parser::default();
+    /// parser.parse_partial(src)?;
+    ///
+    /// let mut parser = sam::header::Parser::default();
+    /// parser.set_require_utf8_values(true);
+    /// assert!(parser.parse_partial(src).is_err());
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn set_require_utf8_values(&mut self, require_utf8_values: bool) {
+        self.ctx.set_require_utf8_values(require_utf8_values);
+    }
+
+    /// Returns the tags of header (`@HD`) fields that were skipped because of invalid values.
+    ///
+    /// This is only populated when skipping invalid fields is enabled (see
+    /// [`Self::set_skip_invalid_fields`]).
+    pub fn skipped_header_tags(&self) -> &[map::header::Tag] {
+        &self.skipped_header_tags
+    }
+
     /// Parses and adds a raw record to the header.
     ///
     /// # Examples
@@ -88,14 +149,76 @@ impl Parser {
     /// # Ok::<_, sam::header::ParseError>(())
     /// ```
     pub fn parse_partial(&mut self, src: &[u8]) -> Result<(), ParseError> {
+        self.update_context(src);
+
+        let record = parse_record(src, &self.ctx, &mut self.skipped_header_tags)
+            .map_err(ParseError::InvalidRecord)?;
+
+        self.add_record(record)
+    }
+
+    /// Parses and adds a raw record to the header, collecting header (`@HD`) field errors.
+    ///
+    /// This is the same as [`Self::parse_partial`], except that invalid and duplicate header
+    /// (`@HD`) field values are skipped and returned as [`HeaderFieldError`]s rather than failing
+    /// the record. This allows recovering the valid fields of a header record. A header record
+    /// still requires a valid version.
+    ///
+    /// Only header (`@HD`) records are parsed leniently. Reference sequence (`@SQ`), read group
+    /// (`@RG`), program (`@PG`), and comment (`@CO`) records are parsed as in
+    /// [`Self::parse_partial`], and the returned list of errors is always empty for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let mut parser = sam::header::Parser::default();
+    /// let errors = parser.parse_partial_lenient(b"@HD\tVN:1.6\tGO:query\tSO:sideways")?;
+    /// assert!(matches!(
+    ///     errors[..],
+    ///     [sam::header::HeaderFieldError::InvalidSortOrder(_)]
+    /// ));
+    ///
+    /// let header = parser.finish();
+    /// let map = header.header().expect("missing header record");
+    /// assert_eq!(map.other_fields().len(), 1);
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn parse_partial_lenient(
+        &mut self,
+        src: &[u8],
+    ) -> Result<Vec<HeaderFieldError>, ParseError> {
+        self.update_context(src);
+
+        let mut header_field_errors = Vec::new();
+
+        let record = parse_record_lenient(
+            src,
+            &self.ctx,
+            &mut self.skipped_header_tags,
+            &mut header_field_errors,
+        )
+        .map_err(ParseError::InvalidRecord)?;
+
+        self.add_record(record)?;
+
+        Ok(header_field_errors)
+    }
+
+    fn update_context(&mut self, src: &[u8]) {
         if self.is_empty() {
             if let Some(version) = extract_version(src) {
+                let skip_invalid_fields = self.ctx.skip_invalid_fields();
+                let require_utf8_values = self.ctx.require_utf8_values();
                 self.ctx = Context::from(version);
+                self.ctx.set_skip_invalid_fields(skip_invalid_fields);
+                self.ctx.set_require_utf8_values(require_utf8_values);
             }
         }
+    }
 
-        let record = parse_record(src, &self.ctx).map_err(ParseError::InvalidRecord)?;
-
+    fn add_record(&mut self, record: Record) -> Result<(), ParseError> {
         match record {
             Record::Header(header) => {
                 if self.is_empty() {
@@ -349,6 +472,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_partial_with_non_utf8_value() -> Result<(), Box<dyn std::error::Error>> {
+        const SRC: &[u8] = b"@RG\tID:rg0\tzn:nd\x80ls";
+
+        let mut parser = Parser::default();
+        parser.parse_partial(SRC)?;
+        let header = parser.finish();
+
+        let read_group = header.read_groups().get(&b"rg0"[..]).unwrap();
+        let zn = map::tag::Other::try_from([b'z', b'n'])?;
+        assert_eq!(
+            read_group.other_fields().get(&zn),
+            Some(&BString::from(&b"nd\x80ls"[..]))
+        );
+        assert!(matches!(read_group.other_field_str(zn), Some(Err(_))));
+
+        let mut parser = Parser::default();
+        parser.set_require_utf8_values(true);
+        assert!(matches!(
+            parser.parse_partial(SRC),
+            Err(ParseError::InvalidRecord(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_partial_with_non_utf8_comment() -> Result<(), ParseError> {
+        const SRC: &[u8] = b"@CO\tnd\x80ls";
+
+        let mut parser = Parser::default();
+        parser.parse_partial(SRC)?;
+        let header = parser.finish();
+        assert_eq!(header.comments(), [BString::from(&b"nd\x80ls"[..])]);
+
+        let mut parser = Parser::default();
+        parser.set_require_utf8_values(true);
+        assert!(matches!(
+            parser.parse_partial(SRC),
+            Err(ParseError::InvalidRecord(_))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_extract_version() {
         assert_eq!(extract_version(b"@HD\tVN:1.6"), Some(Version::new(1, 6)));
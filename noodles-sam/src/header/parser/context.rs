@@ -3,12 +3,30 @@ use crate::header::record::value::map::header::Version;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Context {
     allow_duplicate_tags: bool,
+    skip_invalid_fields: bool,
+    require_utf8_values: bool,
 }
 
 impl Context {
     pub fn allow_duplicate_tags(&self) -> bool {
         self.allow_duplicate_tags
     }
+
+    pub fn skip_invalid_fields(&self) -> bool {
+        self.skip_invalid_fields
+    }
+
+    pub fn set_skip_invalid_fields(&mut self, skip_invalid_fields: bool) {
+        self.skip_invalid_fields = skip_invalid_fields;
+    }
+
+    pub fn require_utf8_values(&self) -> bool {
+        self.require_utf8_values
+    }
+
+    pub fn set_require_utf8_values(&mut self, require_utf8_values: bool) {
+        self.require_utf8_values = require_utf8_values;
+    }
 }
 
 impl Default for Context {
@@ -21,6 +39,8 @@ impl From<Version> for Context {
     fn from(version: Version) -> Self {
         Self {
             allow_duplicate_tags: version < Version::new(1, 6),
+            skip_invalid_fields: false,
+            require_utf8_values: false,
         }
     }
 }
@@ -3,8 +3,11 @@ pub(crate) mod value;
 
 use std::{error, fmt};
 
-use self::{kind::parse_kind, value::parse_value};
-use crate::header::Record;
+use self::{
+    kind::parse_kind,
+    value::{map::header::ParseError as HeaderFieldError, parse_value, parse_value_lenient},
+};
+use crate::header::{record::value::map::header::Tag, Record};
 
 use super::Context;
 
@@ -39,10 +42,32 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub(super) fn parse_record(mut src: &[u8], ctx: &Context) -> Result<Record, ParseError> {
+pub(super) fn parse_record(
+    mut src: &[u8],
+    ctx: &Context,
+    skipped_header_tags: &mut Vec<Tag>,
+) -> Result<Record, ParseError> {
     consume_prefix(&mut src)?;
     let kind = parse_kind(&mut src).map_err(ParseError::InvalidKind)?;
-    parse_value(&mut src, ctx, kind).map_err(ParseError::InvalidValue)
+    parse_value(&mut src, ctx, skipped_header_tags, kind).map_err(ParseError::InvalidValue)
+}
+
+pub(super) fn parse_record_lenient(
+    mut src: &[u8],
+    ctx: &Context,
+    skipped_header_tags: &mut Vec<Tag>,
+    header_field_errors: &mut Vec<HeaderFieldError>,
+) -> Result<Record, ParseError> {
+    consume_prefix(&mut src)?;
+    let kind = parse_kind(&mut src).map_err(ParseError::InvalidKind)?;
+    parse_value_lenient(
+        &mut src,
+        ctx,
+        skipped_header_tags,
+        kind,
+        header_field_errors,
+    )
+    .map_err(ParseError::InvalidValue)
 }
 
 fn consume_prefix(src: &mut &[u8]) -> Result<(), ParseError> {
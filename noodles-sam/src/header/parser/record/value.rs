@@ -4,7 +4,11 @@ pub(crate) mod map;
 use std::{error, fmt};
 
 use self::comment::parse_comment;
-use crate::header::{parser::Context, record::Kind, Record};
+use crate::header::{
+    parser::Context,
+    record::{value::map as record_map, Kind},
+    Record,
+};
 
 /// An error returned when a SAM header record value fails to parse.
 #[allow(clippy::enum_variant_names)]
@@ -49,10 +53,11 @@ impl fmt::Display for ParseError {
 pub(super) fn parse_value(
     src: &mut &[u8],
     ctx: &Context,
+    skipped_header_tags: &mut Vec<record_map::header::Tag>,
     kind: Kind,
 ) -> Result<Record, ParseError> {
     match kind {
-        Kind::Header => map::parse_header(src, ctx)
+        Kind::Header => map::parse_header(src, ctx, skipped_header_tags)
             .map(Record::Header)
             .map_err(ParseError::InvalidHeader),
         Kind::ReferenceSequence => map::parse_reference_sequence(src, ctx)
@@ -64,8 +69,26 @@ pub(super) fn parse_value(
         Kind::Program => map::parse_program(src, ctx)
             .map(|(id, map)| Record::Program(id, map))
             .map_err(ParseError::InvalidProgram),
-        Kind::Comment => parse_comment(src)
+        Kind::Comment => parse_comment(src, ctx)
             .map(Record::Comment)
             .map_err(ParseError::InvalidComment),
     }
 }
+
+pub(super) fn parse_value_lenient(
+    src: &mut &[u8],
+    ctx: &Context,
+    skipped_header_tags: &mut Vec<record_map::header::Tag>,
+    kind: Kind,
+    header_field_errors: &mut Vec<map::header::ParseError>,
+) -> Result<Record, ParseError> {
+    match kind {
+        Kind::Header => map::header::parse_header_lenient(src, ctx)
+            .map(|(map, errors)| {
+                header_field_errors.extend(errors);
+                Record::Header(map)
+            })
+            .map_err(ParseError::InvalidHeader),
+        _ => parse_value(src, ctx, skipped_header_tags, kind),
+    }
+}
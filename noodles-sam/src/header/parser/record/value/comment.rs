@@ -1,28 +1,45 @@
-use std::{error, fmt};
+use std::{error, fmt, str};
 
 use bstr::BString;
 
+use crate::header::parser::Context;
+
 /// An error returned when a SAM header record comment value fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
     /// The delimiter is invalid.
     InvalidDelimiter,
+    /// The comment is not valid UTF-8.
+    InvalidUtf8(str::Utf8Error),
 }
 
-impl error::Error for ParseError {}
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidDelimiter => None,
+            Self::InvalidUtf8(e) => Some(e),
+        }
+    }
+}
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidDelimiter => write!(f, "invalid delimiter"),
+            Self::InvalidUtf8(_) => write!(f, "invalid UTF-8"),
         }
     }
 }
 
-pub(super) fn parse_comment(src: &mut &[u8]) -> Result<BString, ParseError> {
+pub(super) fn parse_comment(src: &mut &[u8], ctx: &Context) -> Result<BString, ParseError> {
     consume_delimiter(src)?;
     let (buf, rest) = src.split_at(src.len());
     *src = rest;
+
+    if ctx.require_utf8_values() {
+        str::from_utf8(buf).map_err(ParseError::InvalidUtf8)?;
+    }
+
     Ok(buf.into())
 }
 
@@ -45,10 +62,37 @@ mod tests {
 
     #[test]
     fn test_parse_comment() {
+        let ctx = Context::default();
+
         let mut src = &b"\tnoodles"[..];
-        assert_eq!(parse_comment(&mut src), Ok(BString::from("noodles")));
+        assert_eq!(parse_comment(&mut src, &ctx), Ok(BString::from("noodles")));
 
         let mut src = &b"noodles"[..];
-        assert_eq!(parse_comment(&mut src), Err(ParseError::InvalidDelimiter));
+        assert_eq!(
+            parse_comment(&mut src, &ctx),
+            Err(ParseError::InvalidDelimiter)
+        );
+    }
+
+    #[test]
+    fn test_parse_comment_with_require_utf8_values() {
+        let mut ctx = Context::default();
+
+        let mut src = &b"\tnd\x80ls"[..];
+        assert_eq!(
+            parse_comment(&mut src, &ctx),
+            Ok(BString::from(&b"nd\x80ls"[..]))
+        );
+
+        ctx.set_require_utf8_values(true);
+
+        let mut src = &b"\tndls"[..];
+        assert_eq!(parse_comment(&mut src, &ctx), Ok(BString::from("ndls")));
+
+        let mut src = &b"\tnd\x80ls"[..];
+        assert!(matches!(
+            parse_comment(&mut src, &ctx),
+            Err(ParseError::InvalidUtf8(_))
+        ));
     }
 }
@@ -1,20 +1,32 @@
-use std::{error, fmt};
+use std::{error, fmt, str};
 
 use bstr::{BStr, ByteSlice};
 
+use crate::header::parser::Context;
+
 /// An error returned when a SAM header record field tag fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
     /// The input is missing.
     Missing,
+    /// The input is not valid UTF-8.
+    InvalidUtf8(str::Utf8Error),
 }
 
-impl error::Error for ParseError {}
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Missing => None,
+            Self::InvalidUtf8(e) => Some(e),
+        }
+    }
+}
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Missing => write!(f, "missing input"),
+            Self::InvalidUtf8(_) => write!(f, "invalid UTF-8"),
         }
     }
 }
@@ -34,6 +46,16 @@ pub fn parse_value<'a>(src: &mut &'a [u8]) -> Result<&'a BStr, ParseError> {
     }
 }
 
+pub fn parse_other_value<'a>(src: &mut &'a [u8], ctx: &Context) -> Result<&'a BStr, ParseError> {
+    let value = parse_value(src)?;
+
+    if ctx.require_utf8_values() {
+        str::from_utf8(value).map_err(ParseError::InvalidUtf8)?;
+    }
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,4 +68,23 @@ mod tests {
         let mut src = &b""[..];
         assert_eq!(parse_value(&mut src), Err(ParseError::Missing));
     }
+
+    #[test]
+    fn test_parse_other_value() {
+        let mut ctx = Context::default();
+
+        let mut src = &b"nd\x80ls"[..];
+        assert_eq!(parse_other_value(&mut src, &ctx), Ok(b"nd\x80ls".as_bstr()));
+
+        ctx.set_require_utf8_values(true);
+
+        let mut src = &b"ndls"[..];
+        assert_eq!(parse_other_value(&mut src, &ctx), Ok(b"ndls".as_bstr()));
+
+        let mut src = &b"nd\x80ls"[..];
+        assert!(matches!(
+            parse_other_value(&mut src, &ctx),
+            Err(ParseError::InvalidUtf8(_))
+        ));
+    }
 }
@@ -11,7 +11,7 @@ use crate::header::{
     record::value::{
         map::{
             self,
-            header::{tag, Tag},
+            header::{group_order, sort_order, tag, Tag, Version},
             tag::Other,
             Header, OtherFields,
         },
@@ -22,13 +22,28 @@ use crate::header::{
 /// An error returned when a SAM header header record value fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
+    /// A field is invalid.
     InvalidField(super::field::ParseError),
+    /// A tag is invalid.
     InvalidTag(super::field::tag::ParseError),
+    /// A value is invalid.
     InvalidValue(value::ParseError),
+    /// The version (`VN`) is missing.
     MissingVersion,
+    /// The version (`VN`) is invalid.
     InvalidVersion(version::ParseError),
+    /// The major version is not a supported SAM format version.
+    UnsupportedVersion(Version),
+    /// An other field value is invalid.
     InvalidOther(Other<tag::Standard>, value::ParseError),
-    DuplicateTag(Tag),
+    /// The sort order (`SO`) is invalid.
+    InvalidSortOrder(BString),
+    /// The group order (`GO`) is invalid.
+    InvalidGroupOrder(BString),
+    /// The subsort order (`SS`) is invalid.
+    InvalidSubsortOrder(BString),
+    /// A tag is duplicated (tag, byte offset of the duplicate tag in the header line).
+    DuplicateTag(Tag, usize),
 }
 
 impl error::Error for ParseError {
@@ -50,31 +65,99 @@ impl fmt::Display for ParseError {
             Self::InvalidValue(_) => write!(f, "invalid value"),
             Self::MissingVersion => write!(f, "missing version ({}) field", tag::VERSION),
             Self::InvalidVersion(_) => write!(f, "invalid version ({})", tag::VERSION),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported version ({}): {version}", tag::VERSION)
+            }
             Self::InvalidOther(tag, _) => write!(f, "invalid other ({tag})"),
-            Self::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
+            Self::InvalidSortOrder(value) => write!(
+                f,
+                "invalid sort order ({}): expected {{unknown, unsorted, queryname, coordinate}}, got {value:?}",
+                tag::SORT_ORDER
+            ),
+            Self::InvalidGroupOrder(value) => write!(
+                f,
+                "invalid group order ({}): expected {{none, query, reference}}, got {value:?}",
+                tag::GROUP_ORDER
+            ),
+            Self::InvalidSubsortOrder(value) => {
+                write!(
+                    f,
+                    "invalid subsort order ({}): {value:?}",
+                    tag::SUBSORT_ORDER
+                )
+            }
+            Self::DuplicateTag(tag, offset) => {
+                write!(f, "duplicate tag at offset {offset}: {tag}")
+            }
         }
     }
 }
 
-pub(crate) fn parse_header(src: &mut &[u8], ctx: &Context) -> Result<Map<Header>, ParseError> {
+pub(crate) fn parse_header(
+    src: &mut &[u8],
+    ctx: &Context,
+    skipped_tags: &mut Vec<Tag>,
+) -> Result<Map<Header>, ParseError> {
+    parse_header_with(src, ctx, |tag, e| match e {
+        ParseError::DuplicateTag(..) => Err(e),
+        _ => skip_or_fail(ctx, skipped_tags, tag, e),
+    })
+}
+
+/// Parses a header record value, collecting field errors rather than failing on them.
+///
+/// Invalid and duplicate field values are collected and skipped. The input is still required
+/// to be well-formed, i.e., malformed delimiters, separators, and tags are fatal, and a valid
+/// version is required.
+pub(crate) fn parse_header_lenient(
+    src: &mut &[u8],
+    ctx: &Context,
+) -> Result<(Map<Header>, Vec<ParseError>), ParseError> {
+    let mut errors = Vec::new();
+
+    let map = parse_header_with(src, ctx, |_, e| {
+        errors.push(e);
+        Ok(())
+    })?;
+
+    Ok((map, errors))
+}
+
+fn parse_header_with<F>(
+    src: &mut &[u8],
+    ctx: &Context,
+    mut on_field_error: F,
+) -> Result<Map<Header>, ParseError>
+where
+    F: FnMut(Tag, ParseError) -> Result<(), ParseError>,
+{
+    // The record prefix and kind (`@HD`) precede the value.
+    const PREFIX_LEN: usize = 3;
+
+    let src_len = src.len();
     let mut version = None;
 
     let mut other_fields = OtherFields::new();
 
     while !src.is_empty() {
         consume_delimiter(src).map_err(ParseError::InvalidField)?;
+        let offset = PREFIX_LEN + (src_len - src.len());
         let tag = parse_tag(src).map_err(ParseError::InvalidTag)?;
         consume_separator(src).map_err(ParseError::InvalidField)?;
 
-        match tag {
-            tag::VERSION => {
-                parse_value(src)
-                    .map_err(ParseError::InvalidValue)
-                    .and_then(|buf| parse_version(buf).map_err(ParseError::InvalidVersion))
-                    .and_then(|v| try_replace(&mut version, ctx, tag::VERSION, v))?;
-            }
-            Tag::Other(t) => parse_other(src, t)
-                .and_then(|value| try_insert(&mut other_fields, ctx, t, value))?,
+        let result = match tag {
+            tag::VERSION => parse_value(src)
+                .map_err(ParseError::InvalidValue)
+                .and_then(|buf| parse_version(buf).map_err(ParseError::InvalidVersion))
+                .and_then(validate_version)
+                .and_then(|v| try_replace(&mut version, ctx, tag::VERSION, offset, v)),
+            Tag::Other(t) => parse_other(src, ctx, t)
+                .and_then(|v| validate_other(t, v))
+                .and_then(|value| try_insert(&mut other_fields, ctx, t, offset, value)),
+        };
+
+        if let Err(e) = result {
+            on_field_error(tag, e)?;
         }
     }
 
@@ -86,60 +169,310 @@ pub(crate) fn parse_header(src: &mut &[u8], ctx: &Context) -> Result<Map<Header>
     })
 }
 
-fn parse_other<'a>(src: &mut &'a [u8], tag: Other<tag::Standard>) -> Result<&'a BStr, ParseError> {
-    parse_value(src).map_err(|e| ParseError::InvalidOther(tag, e))
+fn validate_version(version: Version) -> Result<Version, ParseError> {
+    const SUPPORTED_MAJOR_VERSION: u32 = 1;
+
+    if version.major() == SUPPORTED_MAJOR_VERSION {
+        Ok(version)
+    } else {
+        Err(ParseError::UnsupportedVersion(version))
+    }
+}
+
+fn validate_other(tag: Other<tag::Standard>, value: &BStr) -> Result<&BStr, ParseError> {
+    if tag == tag::SORT_ORDER {
+        if sort_order::SortOrder::try_from(value.as_ref()).is_err() {
+            return Err(ParseError::InvalidSortOrder(value.into()));
+        }
+    } else if tag == tag::GROUP_ORDER && !is_valid_group_order(value) {
+        return Err(ParseError::InvalidGroupOrder(value.into()));
+    } else if tag == tag::SUBSORT_ORDER && !is_valid_subsort_order(value) {
+        return Err(ParseError::InvalidSubsortOrder(value.into()));
+    }
+
+    Ok(value)
+}
+
+fn is_valid_group_order(s: &[u8]) -> bool {
+    matches!(
+        s,
+        group_order::NONE | group_order::QUERY | group_order::REFERENCE
+    )
+}
+
+// § 1.3.1 "File-level metadata" (2023-05-24): `SS` matches
+// `(coordinate|queryname|unsorted)(:[A-Za-z0-9_-]+)+`.
+fn is_valid_subsort_order(s: &[u8]) -> bool {
+    const DELIMITER: u8 = b':';
+
+    fn is_valid_sub_sort(s: &[u8]) -> bool {
+        !s.is_empty()
+            && s.iter()
+                .all(|&b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-'))
+    }
+
+    let mut components = s.split(|&b| b == DELIMITER);
+
+    let is_valid_sort_order = matches!(
+        components.next(),
+        Some(sort_order::COORDINATE | sort_order::QUERY_NAME | sort_order::UNSORTED)
+    );
+
+    let mut sub_sorts = components.peekable();
+
+    is_valid_sort_order && sub_sorts.peek().is_some() && sub_sorts.all(is_valid_sub_sort)
+}
+
+fn parse_other<'a>(
+    src: &mut &'a [u8],
+    ctx: &Context,
+    tag: Other<tag::Standard>,
+) -> Result<&'a BStr, ParseError> {
+    value::parse_other_value(src, ctx).map_err(|e| ParseError::InvalidOther(tag, e))
+}
+
+fn skip_or_fail(
+    ctx: &Context,
+    skipped_tags: &mut Vec<Tag>,
+    tag: Tag,
+    e: ParseError,
+) -> Result<(), ParseError> {
+    if ctx.skip_invalid_fields() {
+        skipped_tags.push(tag);
+        Ok(())
+    } else {
+        Err(e)
+    }
 }
 
 fn try_replace<T>(
     option: &mut Option<T>,
     ctx: &Context,
     tag: Tag,
+    offset: usize,
     value: T,
 ) -> Result<(), ParseError> {
-    if option.replace(value).is_some() && !ctx.allow_duplicate_tags() {
-        Err(ParseError::DuplicateTag(tag))
-    } else {
-        Ok(())
+    // The first value is kept when a duplicate is rejected.
+    if option.is_some() && !ctx.allow_duplicate_tags() {
+        return Err(ParseError::DuplicateTag(tag, offset));
     }
+
+    *option = Some(value);
+
+    Ok(())
 }
 
 fn try_insert<V>(
     other_fields: &mut OtherFields<tag::Standard>,
     ctx: &Context,
     tag: map::tag::Other<tag::Standard>,
+    offset: usize,
     value: V,
 ) -> Result<(), ParseError>
 where
     V: Into<BString>,
 {
-    if other_fields.insert(tag, value.into()).is_some() && !ctx.allow_duplicate_tags() {
-        Err(ParseError::DuplicateTag(Tag::Other(tag)))
-    } else {
-        Ok(())
+    if other_fields.contains_key(&tag) && !ctx.allow_duplicate_tags() {
+        return Err(ParseError::DuplicateTag(Tag::Other(tag), offset));
     }
+
+    other_fields.insert(tag, value.into());
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::header::record::value::map::header::Version;
 
     #[test]
     fn test_parse_header() {
         let mut src = &b"\tVN:1.6"[..];
         let ctx = Context::default();
         assert_eq!(
-            parse_header(&mut src, &ctx),
+            parse_header(&mut src, &ctx, &mut Vec::new()),
             Ok(Map::<Header>::new(Version::new(1, 6)))
         );
     }
 
+    #[test]
+    fn test_parse_header_with_unsupported_version() {
+        let ctx = Context::default();
+
+        let mut src = &b"\tVN:2.0"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::UnsupportedVersion(Version::new(2, 0)))
+        );
+
+        let mut src = &b"\tVN:x.y"[..];
+        assert!(matches!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::InvalidVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_header_with_duplicate_tag() {
+        let ctx = Context::default();
+
+        // `@HD\tVN:1.6\tVN:1.6`
+        let mut src = &b"\tVN:1.6\tVN:1.6"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::DuplicateTag(tag::VERSION, 11))
+        );
+
+        // `@HD\tVN:1.6\tSO:coordinate\tSO:unsorted`
+        let mut src = &b"\tVN:1.6\tSO:coordinate\tSO:unsorted"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::DuplicateTag(Tag::Other(tag::SORT_ORDER), 25))
+        );
+    }
+
+    #[test]
+    fn test_parse_header_with_skip_invalid_fields() {
+        let mut ctx = Context::default();
+
+        let mut src = &b"\tVN:1.6\tSO:\tGO:query"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::InvalidOther(
+                tag::SORT_ORDER,
+                value::ParseError::Missing
+            ))
+        );
+
+        ctx.set_skip_invalid_fields(true);
+
+        let mut src = &b"\tVN:x\tVN:1.6\tSO:\tGO:query"[..];
+        let mut skipped_tags = Vec::new();
+        let actual = parse_header(&mut src, &ctx, &mut skipped_tags);
+
+        let expected = Map::<Header>::builder()
+            .set_version(Version::new(1, 6))
+            .insert(tag::GROUP_ORDER, "query")
+            .build()
+            .unwrap();
+
+        assert_eq!(actual, Ok(expected));
+        assert_eq!(skipped_tags, [tag::VERSION, Tag::Other(tag::SORT_ORDER)]);
+    }
+
+    #[test]
+    fn test_parse_header_with_sort_orders() {
+        let ctx = Context::default();
+
+        let mut src = &b"\tVN:1.6\tSO:coordinate\tGO:query\tSS:coordinate:MI"[..];
+        let expected = Map::<Header>::builder()
+            .set_version(Version::new(1, 6))
+            .insert(tag::SORT_ORDER, "coordinate")
+            .insert(tag::GROUP_ORDER, "query")
+            .insert(tag::SUBSORT_ORDER, "coordinate:MI")
+            .build()
+            .unwrap();
+        assert_eq!(parse_header(&mut src, &ctx, &mut Vec::new()), Ok(expected));
+
+        let mut src = &b"\tVN:1.6\tSO:sideways"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::InvalidSortOrder(BString::from("sideways")))
+        );
+
+        let mut src = &b"\tVN:1.6\tGO:sideways"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::InvalidGroupOrder(BString::from("sideways")))
+        );
+
+        let mut src = &b"\tVN:1.6\tSS:coordinate"[..];
+        assert_eq!(
+            parse_header(&mut src, &ctx, &mut Vec::new()),
+            Err(ParseError::InvalidSubsortOrder(BString::from("coordinate")))
+        );
+    }
+
+    #[test]
+    fn test_parse_header_lenient() {
+        let ctx = Context::default();
+
+        let mut src = &b"\tVN:1.6\tGO:query\tSO:sideways"[..];
+        let (map, errors) = parse_header_lenient(&mut src, &ctx).unwrap();
+
+        let expected = Map::<Header>::builder()
+            .set_version(Version::new(1, 6))
+            .insert(tag::GROUP_ORDER, "query")
+            .build()
+            .unwrap();
+        assert_eq!(map, expected);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors,
+            [ParseError::InvalidSortOrder(BString::from("sideways"))]
+        );
+
+        let mut src = &b"\tVN:x\tGO:query"[..];
+        assert_eq!(
+            parse_header_lenient(&mut src, &ctx),
+            Err(ParseError::MissingVersion)
+        );
+
+        let mut src = &b"\tVN:1.6\tGO:query\tVN:1.5\tGO:reference"[..];
+        let (map, errors) = parse_header_lenient(&mut src, &ctx).unwrap();
+
+        let expected = Map::<Header>::builder()
+            .set_version(Version::new(1, 6))
+            .insert(tag::GROUP_ORDER, "query")
+            .build()
+            .unwrap();
+        assert_eq!(map, expected);
+
+        assert_eq!(
+            errors,
+            [
+                ParseError::DuplicateTag(tag::VERSION, 20),
+                ParseError::DuplicateTag(Tag::Other(tag::GROUP_ORDER), 27),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_valid_subsort_order() {
+        assert!(is_valid_subsort_order(b"coordinate:MI"));
+        assert!(is_valid_subsort_order(b"queryname:natural"));
+        assert!(is_valid_subsort_order(b"unsorted:a_b-c:d"));
+
+        assert!(!is_valid_subsort_order(b""));
+        assert!(!is_valid_subsort_order(b"coordinate"));
+        assert!(!is_valid_subsort_order(b"coordinate:"));
+        assert!(!is_valid_subsort_order(b"unknown:MI"));
+        assert!(!is_valid_subsort_order(b"coordinate:M I"));
+    }
+
+    #[test]
+    fn test_parse_header_preserves_other_fields_order() {
+        let ctx = Context::default();
+        let mut src = &b"\tVN:1.6\tYY:b\tXX:a"[..];
+
+        let header = parse_header(&mut src, &ctx, &mut Vec::new()).unwrap();
+        let actual: Vec<_> = header
+            .other_fields()
+            .iter()
+            .map(|(tag, value)| (tag.as_ref(), value.as_slice()))
+            .collect();
+
+        let expected: [(&[u8; 2], &[u8]); 2] = [(b"YY", b"b"), (b"XX", b"a")];
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_parse_header_with_missing_version() {
         let mut src = &b"\tSO:coordinate"[..];
         let ctx = Context::default();
         assert_eq!(
-            parse_header(&mut src, &ctx),
+            parse_header(&mut src, &ctx, &mut Vec::new()),
             Err(ParseError::MissingVersion)
         );
     }
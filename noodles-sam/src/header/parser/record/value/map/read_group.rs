@@ -69,7 +69,7 @@ pub(crate) fn parse_read_group(
 
         match tag {
             tag::ID => parse_id(src).and_then(|v| try_replace(&mut id, ctx, tag::ID, v))?,
-            Tag::Other(t) => parse_other(src, t)
+            Tag::Other(t) => parse_other(src, ctx, t)
                 .and_then(|value| try_insert(&mut other_fields, ctx, t, value))?,
         }
     }
@@ -89,8 +89,12 @@ fn parse_id<'a>(src: &mut &'a [u8]) -> Result<&'a BStr, ParseError> {
     parse_value(src).map_err(ParseError::InvalidId)
 }
 
-fn parse_other<'a>(src: &mut &'a [u8], tag: Other<tag::Standard>) -> Result<&'a BStr, ParseError> {
-    parse_value(src).map_err(|e| ParseError::InvalidOther(tag, e))
+fn parse_other<'a>(
+    src: &mut &'a [u8],
+    ctx: &Context,
+    tag: Other<tag::Standard>,
+) -> Result<&'a BStr, ParseError> {
+    value::parse_other_value(src, ctx).map_err(|e| ParseError::InvalidOther(tag, e))
 }
 
 fn try_replace<T>(
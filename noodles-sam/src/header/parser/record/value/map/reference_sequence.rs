@@ -78,7 +78,7 @@ pub(crate) fn parse_reference_sequence(
             tag::LENGTH => parse_length(src)
                 .map_err(ParseError::InvalidLength)
                 .and_then(|v| try_replace(&mut length, ctx, tag::LENGTH, v))?,
-            Tag::Other(t) => parse_other(src, t)
+            Tag::Other(t) => parse_other(src, ctx, t)
                 .and_then(|value| try_insert(&mut other_fields, ctx, t, value))?,
         }
     }
@@ -99,8 +99,12 @@ fn parse_name<'a>(src: &mut &'a [u8]) -> Result<&'a BStr, ParseError> {
     parse_value(src).map_err(ParseError::InvalidValue)
 }
 
-fn parse_other<'a>(src: &mut &'a [u8], tag: Other<tag::Standard>) -> Result<&'a BStr, ParseError> {
-    parse_value(src).map_err(|e| ParseError::InvalidOther(tag, e))
+fn parse_other<'a>(
+    src: &mut &'a [u8],
+    ctx: &Context,
+    tag: Other<tag::Standard>,
+) -> Result<&'a BStr, ParseError> {
+    value::parse_other_value(src, ctx).map_err(|e| ParseError::InvalidOther(tag, e))
 }
 
 fn try_replace<T>(
@@ -12,6 +12,8 @@ pub use self::{
     reference_sequence::ReferenceSequence, tag::Tag,
 };
 
+use std::str;
+
 use bstr::BString;
 use indexmap::IndexMap;
 
@@ -46,10 +48,43 @@ where
     }
 
     /// Returns the nonstandard fields in the map.
+    ///
+    /// Fields are kept in insertion order, i.e., when parsed, the order they appear in the
+    /// record. This order is preserved when the map is written.
     pub fn other_fields(&self) -> &OtherFields<I::StandardTag> {
         &self.other_fields
     }
 
+    /// Returns the value of a nonstandard field as a string.
+    ///
+    /// This returns `None` if the field is not in the map and an error if the value is not valid
+    /// UTF-8, e.g., Latin-1 encoded text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{tag, Header}, Map};
+    ///
+    /// let nd = tag::Other::try_from([b'n', b'd'])?;
+    /// let ls = tag::Other::try_from([b'l', b's'])?;
+    ///
+    /// let mut map = Map::<Header>::new(Default::default());
+    /// map.other_fields_mut().insert(nd, "ndls".into());
+    /// map.other_fields_mut().insert(ls, b"nd\x80ls".into());
+    ///
+    /// assert_eq!(map.other_field_str(nd), Some(Ok("ndls")));
+    /// assert!(matches!(map.other_field_str(ls), Some(Err(_))));
+    /// # Ok::<_, tag::ParseError>(())
+    /// ```
+    pub fn other_field_str(
+        &self,
+        tag: tag::Other<I::StandardTag>,
+    ) -> Option<Result<&str, str::Utf8Error>> {
+        self.other_fields
+            .get(&tag)
+            .map(|value| str::from_utf8(value.as_ref()))
+    }
+
     /// Returns a mutable reference to the nonstandard fields in the map.
     ///
     /// # Example
@@ -74,6 +74,39 @@ impl Map<Header> {
     pub fn version_mut(&mut self) -> &mut Version {
         &mut self.inner.version
     }
+
+    /// Sets the format version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::Version}, Map};
+    /// let mut header = Map::<map::Header>::new(Version::new(1, 6));
+    /// header.set_version(Version::new(1, 4));
+    /// assert_eq!(header.version(), Version::new(1, 4));
+    /// ```
+    pub fn set_version(&mut self, version: Version) {
+        self.inner.version = version;
+    }
+
+    /// Raises the format version to at least the given version.
+    ///
+    /// The version is only changed if it is older than `min`, i.e., this never downgrades the
+    /// version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::Version}, Map};
+    /// let mut header = Map::<map::Header>::new(Version::new(1, 4));
+    /// header.bump_to(Version::new(1, 6));
+    /// assert_eq!(header.version(), Version::new(1, 6));
+    /// ```
+    pub fn bump_to(&mut self, min: Version) {
+        if self.inner.version < min {
+            self.inner.version = min;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -85,4 +118,15 @@ mod tests {
         let header = Map::<Header>::default();
         assert_eq!(header.version(), Version::default());
     }
+
+    #[test]
+    fn test_bump_to() {
+        let mut header = Map::<Header>::new(Version::new(1, 4));
+
+        header.bump_to(Version::new(1, 6));
+        assert_eq!(header.version(), Version::new(1, 6));
+
+        header.bump_to(Version::new(1, 3));
+        assert_eq!(header.version(), Version::new(1, 6));
+    }
 }
@@ -1,5 +1,7 @@
 //! SAM header header sort order.
 
+use std::{error, fmt};
+
 /// The record order is unknown (`unknown`).
 pub const UNKNOWN: &[u8] = b"unknown";
 
@@ -11,3 +13,88 @@ pub const QUERY_NAME: &[u8] = b"queryname";
 
 /// Records are sorted by reference sequence and position (`coordinate`).
 pub const COORDINATE: &[u8] = b"coordinate";
+
+/// A SAM header header sort order.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SortOrder {
+    /// The record order is unknown (`unknown`).
+    Unknown,
+    /// Records are not sorted (`unsorted`).
+    Unsorted,
+    /// Records are sorted by name (`queryname`).
+    QueryName,
+    /// Records are sorted by reference sequence and position (`coordinate`).
+    Coordinate,
+}
+
+impl AsRef<[u8]> for SortOrder {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Unknown => UNKNOWN,
+            Self::Unsorted => UNSORTED,
+            Self::QueryName => QUERY_NAME,
+            Self::Coordinate => COORDINATE,
+        }
+    }
+}
+
+/// An error returned when a raw SAM header header sort order fails to convert.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TryFromError(Vec<u8>);
+
+impl error::Error for TryFromError {}
+
+impl fmt::Display for TryFromError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid sort order: expected {{unknown, unsorted, queryname, coordinate}}, got {:?}",
+            String::from_utf8_lossy(&self.0)
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for SortOrder {
+    type Error = TryFromError;
+
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        match s {
+            UNKNOWN => Ok(Self::Unknown),
+            UNSORTED => Ok(Self::Unsorted),
+            QUERY_NAME => Ok(Self::QueryName),
+            COORDINATE => Ok(Self::Coordinate),
+            _ => Err(TryFromError(s.to_vec())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_u8_slice_for_sort_order() {
+        assert_eq!(SortOrder::try_from(&b"unknown"[..]), Ok(SortOrder::Unknown));
+        assert_eq!(
+            SortOrder::try_from(&b"unsorted"[..]),
+            Ok(SortOrder::Unsorted)
+        );
+        assert_eq!(
+            SortOrder::try_from(&b"queryname"[..]),
+            Ok(SortOrder::QueryName)
+        );
+        assert_eq!(
+            SortOrder::try_from(&b"coordinate"[..]),
+            Ok(SortOrder::Coordinate)
+        );
+        assert_eq!(
+            SortOrder::try_from(&b"ndls"[..]),
+            Err(TryFromError(b"ndls".to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_as_ref_u8_slice_for_sort_order() {
+        assert_eq!(SortOrder::Coordinate.as_ref(), b"coordinate");
+    }
+}
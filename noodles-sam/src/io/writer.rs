@@ -2,7 +2,7 @@
 
 mod builder;
 mod header;
-mod num;
+pub(crate) mod num;
 pub mod record;
 
 use std::io::{self, Write};
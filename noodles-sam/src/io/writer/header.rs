@@ -85,4 +85,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_header_preserves_other_fields_order() -> Result<(), Box<dyn std::error::Error>> {
+        for src in ["@HD\tVN:1.6\tXX:a\tYY:b\n", "@HD\tVN:1.6\tYY:b\tXX:a\n"] {
+            let header: Header = src.parse()?;
+
+            let mut buf = Vec::new();
+            write_header(&mut buf, &header)?;
+
+            assert_eq!(buf, src.as_bytes());
+        }
+
+        Ok(())
+    }
 }
@@ -13,7 +13,7 @@ mod template_length;
 
 use std::io::{self, Write};
 
-pub use self::cigar::write_cigar;
+pub use self::{cigar::write_cigar, data::write_data_tsv};
 use self::{
     data::write_data,
     flags::write_flags,
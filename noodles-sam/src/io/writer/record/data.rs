@@ -2,7 +2,7 @@ mod field;
 
 use std::io::{self, Write};
 
-use self::field::write_field;
+use self::field::{write_field, write_tag, write_type, write_value};
 use crate::alignment::record::Data;
 
 pub(super) fn write_data<W, D>(writer: &mut W, data: D) -> io::Result<()>
@@ -22,6 +22,55 @@ where
     Ok(())
 }
 
+/// Writes alignment record data fields as tab-separated rows.
+///
+/// Each field is written on its own line as `TAG\tTYPE\tVALUE`. Values use the SAM
+/// representation, i.e., arrays are written as the subtype followed by comma-separated values
+/// (e.g., `i,1,2`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{
+///     alignment::{
+///         record::data::field::Tag,
+///         record_buf::{data::field::Value, Data},
+///     },
+///     io::writer::record::write_data_tsv,
+/// };
+///
+/// let data: Data = [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))]
+///     .into_iter()
+///     .collect();
+///
+/// let mut buf = Vec::new();
+/// write_data_tsv(&mut buf, &data)?;
+/// assert_eq!(buf, b"NH\ti\t1\n");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_data_tsv<W, D>(writer: &mut W, data: D) -> io::Result<()>
+where
+    W: Write,
+    D: Data,
+{
+    const DELIMITER: u8 = b'\t';
+    const LINE_FEED: u8 = b'\n';
+
+    for result in data.iter() {
+        let (tag, value) = result?;
+
+        write_tag(writer, tag)?;
+        writer.write_all(&[DELIMITER])?;
+        write_type(writer, value.ty())?;
+        writer.write_all(&[DELIMITER])?;
+        write_value(writer, &value)?;
+        writer.write_all(&[LINE_FEED])?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,4 +95,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_data_tsv() -> io::Result<()> {
+        use crate::alignment::{
+            record::data::field::Tag,
+            record_buf::data::field::{value::Array, Value},
+        };
+
+        let mut buf = Vec::new();
+
+        let data: DataBuf = [
+            (Tag::COMMENT, Value::from("noodles")),
+            (
+                Tag::new(b'Z', b'A'),
+                Value::Array(Array::Int32(vec![1, -2])),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        write_data_tsv(&mut buf, &data)?;
+
+        assert_eq!(buf, b"CO\tZ\tnoodles\nZA\tB\ti,1,-2\n");
+
+        Ok(())
+    }
 }
@@ -4,7 +4,7 @@ mod value;
 
 use std::io::{self, Write};
 
-use self::{tag::write_tag, ty::write_type, value::write_value};
+pub(super) use self::{tag::write_tag, ty::write_type, value::write_value};
 use crate::alignment::record::data::field::{Tag, Value};
 
 pub fn write_field<W>(writer: &mut W, tag: Tag, value: &Value) -> io::Result<()>
@@ -2,6 +2,12 @@ use std::io::{self, Write};
 
 use crate::io::writer::num;
 
+/// Writes a SAM record data field float value.
+///
+/// Floats are written using the shortest representation that round-trips to the same `f32`.
+/// Trailing fractional zeros are trimmed, i.e., integral values are written without a decimal
+/// point (e.g., `0.0` is written as `0`). Values are still parsed as floats since the field type
+/// (`f`) is written separately.
 pub(super) fn write_float<W>(writer: &mut W, n: f32) -> io::Result<()>
 where
     W: Write,
@@ -30,6 +36,19 @@ mod tests {
         write_float(&mut buf, 0.0)?;
         assert_eq!(buf, b"0");
 
+        buf.clear();
+        write_float(&mut buf, 1.5)?;
+        assert_eq!(buf, b"1.5");
+
+        let n = std::f32::consts::PI;
+        buf.clear();
+        write_float(&mut buf, n)?;
+        assert_eq!(buf, b"3.1415927");
+        assert_eq!(
+            std::str::from_utf8(&buf).ok().and_then(|s| s.parse().ok()),
+            Some(n)
+        );
+
         buf.clear();
         assert!(matches!(
             write_float(&mut buf, f32::NAN),
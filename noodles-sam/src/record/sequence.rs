@@ -36,6 +36,10 @@ impl<'a> crate::alignment::record::Sequence for Sequence<'a> {
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
         Box::new(self.as_ref().iter().copied())
     }
+
+    fn get(&self, i: usize) -> Option<u8> {
+        self.as_ref().get(i).copied()
+    }
 }
 
 impl<'a> From<Sequence<'a>> for crate::alignment::record_buf::Sequence {
@@ -13,6 +13,7 @@ pub use self::{
     alternative_allele::parse_alternative_allele, contig::parse_contig, filter::parse_filter,
     format::parse_format, info::parse_info, other::parse_other,
 };
+use crate::header::record::Key;
 
 const PREFIX: u8 = b'<';
 
@@ -68,6 +69,13 @@ pub fn consume_suffix(src: &mut &[u8]) -> Result<(), ParseError> {
     }
 }
 
+fn write_missing_key_error<T>(f: &mut fmt::Formatter<'_>, key: Key, tag: T) -> fmt::Result
+where
+    T: fmt::Display,
+{
+    write!(f, "missing required key '{tag}' in {key} definition")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,6 +66,34 @@ pub fn consume_separator(src: &mut &[u8]) -> Result<bool, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_field_with_quoted_description() {
+        let mut src = &br#"Description="x, y",Number=1>"#[..];
+        assert_eq!(
+            split_field(&mut src),
+            Ok(Some(("Description", Cow::from("x, y"))))
+        );
+        assert_eq!(src, b"Number=1>");
+
+        let mut src = &br#"Description="a \"b, c\" d">"#[..];
+        assert_eq!(
+            split_field(&mut src),
+            Ok(Some(("Description", Cow::from(r#"a "b, c" d"#))))
+        );
+        assert_eq!(src, b">");
+
+        let mut src = &br#"Description="x, y>"#[..];
+        let e = split_field(&mut src).unwrap_err();
+        assert_eq!(
+            e,
+            ParseError::InvalidValue(
+                String::from("Description"),
+                value::ParseError::InvalidString(value::string::ParseError::UnexpectedEof)
+            )
+        );
+        assert_eq!(e.to_string(), "invalid value for Description");
+    }
+
     #[test]
     fn test_consume_separator() {
         let mut src = &b","[..];
@@ -1,12 +1,15 @@
 use std::{error, fmt, num};
 
-use crate::header::record::value::{
-    map::{
-        self,
-        filter::{tag, Tag},
-        Filter, OtherFields,
+use crate::header::record::{
+    key,
+    value::{
+        map::{
+            self,
+            filter::{tag, Tag},
+            Filter, OtherFields,
+        },
+        Map,
     },
-    Map,
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -52,8 +55,10 @@ impl fmt::Display for ParseError {
         match &self.kind {
             ParseErrorKind::InvalidMap(_) => write!(f, "invalid map"),
             ParseErrorKind::InvalidField(_) => write!(f, "invalid field"),
-            ParseErrorKind::MissingId => write!(f, "missing ID"),
-            ParseErrorKind::MissingDescription => write!(f, "missing description"),
+            ParseErrorKind::MissingId => super::write_missing_key_error(f, key::FILTER, tag::ID),
+            ParseErrorKind::MissingDescription => {
+                super::write_missing_key_error(f, key::FILTER, tag::DESCRIPTION)
+            }
             ParseErrorKind::InvalidIdx(_) => write!(f, "invalid IDX"),
             ParseErrorKind::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
         }
@@ -158,4 +163,21 @@ mod tests {
 
         assert_eq!(parse_filter(&mut src), Ok(expected));
     }
+
+    #[test]
+    fn test_fmt_with_missing_key() {
+        fn t(mut src: &[u8], expected: &str) {
+            let e = parse_filter(&mut src).unwrap_err();
+            assert_eq!(e.to_string(), expected);
+        }
+
+        t(
+            br#"<Description="Quality below 10">"#,
+            "missing required key 'ID' in FILTER definition",
+        );
+        t(
+            br#"<ID=q10>"#,
+            "missing required key 'Description' in FILTER definition",
+        );
+    }
 }
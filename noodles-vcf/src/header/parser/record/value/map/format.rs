@@ -2,13 +2,16 @@ use std::{error, fmt, num};
 
 use crate::header::{
     number,
-    record::value::{
-        map::{
-            self,
-            format::{tag, ty, Tag, Type},
-            Format, OtherFields,
+    record::{
+        key,
+        value::{
+            map::{
+                self,
+                format::{tag, ty, Tag, Type},
+                Format, OtherFields,
+            },
+            Map,
         },
-        Map,
     },
     FileFormat, Number,
 };
@@ -62,12 +65,18 @@ impl fmt::Display for ParseError {
         match &self.kind {
             ParseErrorKind::InvalidMap(_) => write!(f, "invalid map"),
             ParseErrorKind::InvalidField(_) => write!(f, "invalid field"),
-            ParseErrorKind::MissingId => write!(f, "missing ID"),
-            ParseErrorKind::MissingNumber => write!(f, "missing number"),
+            ParseErrorKind::MissingId => super::write_missing_key_error(f, key::FORMAT, tag::ID),
+            ParseErrorKind::MissingNumber => {
+                super::write_missing_key_error(f, key::FORMAT, tag::NUMBER)
+            }
             ParseErrorKind::InvalidNumber(_) => write!(f, "invalid number"),
-            ParseErrorKind::MissingType => write!(f, "missing type"),
+            ParseErrorKind::MissingType => {
+                super::write_missing_key_error(f, key::FORMAT, tag::TYPE)
+            }
             ParseErrorKind::InvalidType(_) => write!(f, "invalid type"),
-            ParseErrorKind::MissingDescription => write!(f, "missing description"),
+            ParseErrorKind::MissingDescription => {
+                super::write_missing_key_error(f, key::FORMAT, tag::DESCRIPTION)
+            }
             ParseErrorKind::InvalidIdx(_) => write!(f, "invalid IDX"),
             ParseErrorKind::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
         }
@@ -203,4 +212,29 @@ mod tests {
 
         assert_eq!(parse_format(&mut src, file_format), Ok(expected));
     }
+
+    #[test]
+    fn test_fmt_with_missing_key() {
+        fn t(mut src: &[u8], expected: &str) {
+            let e = parse_format(&mut src, FileFormat::new(4, 4)).unwrap_err();
+            assert_eq!(e.to_string(), expected);
+        }
+
+        t(
+            br#"<Number=1,Type=String,Description="Genotype">"#,
+            "missing required key 'ID' in FORMAT definition",
+        );
+        t(
+            br#"<ID=GT,Type=String,Description="Genotype">"#,
+            "missing required key 'Number' in FORMAT definition",
+        );
+        t(
+            br#"<ID=GT,Number=1,Description="Genotype">"#,
+            "missing required key 'Type' in FORMAT definition",
+        );
+        t(
+            br#"<ID=GT,Number=1,Type=String>"#,
+            "missing required key 'Description' in FORMAT definition",
+        );
+    }
 }
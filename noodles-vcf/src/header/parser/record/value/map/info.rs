@@ -2,13 +2,16 @@ use std::{error, fmt, num};
 
 use crate::header::{
     number,
-    record::value::{
-        map::{
-            self,
-            info::{tag, ty, Tag, Type},
-            Info, OtherFields,
+    record::{
+        key,
+        value::{
+            map::{
+                self,
+                info::{tag, ty, Tag, Type},
+                Info, OtherFields,
+            },
+            Map,
         },
-        Map,
     },
     FileFormat, Number,
 };
@@ -62,12 +65,16 @@ impl fmt::Display for ParseError {
         match &self.kind {
             ParseErrorKind::InvalidMap(_) => write!(f, "invalid map"),
             ParseErrorKind::InvalidField(_) => write!(f, "invalid field"),
-            ParseErrorKind::MissingId => write!(f, "missing ID"),
-            ParseErrorKind::MissingNumber => write!(f, "missing number"),
+            ParseErrorKind::MissingId => super::write_missing_key_error(f, key::INFO, tag::ID),
+            ParseErrorKind::MissingNumber => {
+                super::write_missing_key_error(f, key::INFO, tag::NUMBER)
+            }
             ParseErrorKind::InvalidNumber(_) => write!(f, "invalid number"),
-            ParseErrorKind::MissingType => write!(f, "missing type"),
+            ParseErrorKind::MissingType => super::write_missing_key_error(f, key::INFO, tag::TYPE),
             ParseErrorKind::InvalidType(_) => write!(f, "invalid type"),
-            ParseErrorKind::MissingDescription => write!(f, "missing description"),
+            ParseErrorKind::MissingDescription => {
+                super::write_missing_key_error(f, key::INFO, tag::DESCRIPTION)
+            }
             ParseErrorKind::InvalidIdx(_) => write!(f, "invalid IDX"),
             ParseErrorKind::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
         }
@@ -204,4 +211,47 @@ mod tests {
 
         assert_eq!(parse_info(&mut src, file_format), Ok(expected));
     }
+
+    #[test]
+    fn test_parse_info_with_other_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let mut src = &br#"<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",Foo=Bar,Source="noodles">"#[..];
+        let file_format = FileFormat::new(4, 4);
+
+        let id = key::SAMPLES_WITH_DATA_COUNT;
+        let mut map = Map::<Info>::from(id);
+        map.other_fields_mut()
+            .insert("Foo".parse()?, String::from("Bar"));
+        map.other_fields_mut()
+            .insert("Source".parse()?, String::from("noodles"));
+        let expected = (String::from(id), map);
+
+        assert_eq!(parse_info(&mut src, file_format), Ok(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_with_missing_key() {
+        fn t(mut src: &[u8], expected: &str) {
+            let e = parse_info(&mut src, FileFormat::new(4, 4)).unwrap_err();
+            assert_eq!(e.to_string(), expected);
+        }
+
+        t(
+            br#"<Number=1,Type=Integer,Description="Number of samples with data">"#,
+            "missing required key 'ID' in INFO definition",
+        );
+        t(
+            br#"<ID=NS,Type=Integer,Description="Number of samples with data">"#,
+            "missing required key 'Number' in INFO definition",
+        );
+        t(
+            br#"<ID=NS,Number=1,Description="Number of samples with data">"#,
+            "missing required key 'Type' in INFO definition",
+        );
+        t(
+            br#"<ID=NS,Number=1,Type=Integer>"#,
+            "missing required key 'Description' in INFO definition",
+        );
+    }
 }
@@ -7,7 +7,7 @@ pub(crate) mod record;
 pub mod record_buf;
 mod record_bufs;
 
-use self::record::read_record;
+use self::record::{read_record_with_field_delimiter, validate_samples, DEFAULT_FIELD_DELIMITER};
 pub(crate) use self::record_buf::parse_record_buf;
 pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
 
@@ -50,6 +50,9 @@ use crate::{variant::RecordBuf, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    strict_samples: bool,
+    field_delimiter: u8,
+    max_record_length: Option<usize>,
 }
 
 impl<R> Reader<R>
@@ -74,6 +77,9 @@ where
         Self {
             inner,
             buf: String::new(),
+            strict_samples: false,
+            field_delimiter: DEFAULT_FIELD_DELIMITER,
+            max_record_length: None,
         }
     }
 
@@ -182,7 +188,14 @@ where
     ) -> io::Result<usize> {
         self.buf.clear();
 
-        match read_line(&mut self.inner, &mut self.buf)? {
+        let n = match self.max_record_length {
+            Some(max_record_length) => read_limited(&mut self.inner, max_record_length, |src| {
+                read_line(src, &mut self.buf)
+            })?,
+            None => read_line(&mut self.inner, &mut self.buf)?,
+        };
+
+        match n {
             0 => Ok(0),
             n => {
                 parse_record_buf(&self.buf, header, record)
@@ -248,7 +261,75 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let field_delimiter = self.field_delimiter;
+
+        let n = match self.max_record_length {
+            Some(max_record_length) => read_limited(&mut self.inner, max_record_length, |src| {
+                read_record_with_field_delimiter(src, record, field_delimiter)
+            })?,
+            None => read_record_with_field_delimiter(&mut self.inner, record, field_delimiter)?,
+        };
+
+        if self.strict_samples && n > 0 {
+            validate_samples(record)?;
+        }
+
+        Ok(n)
+    }
+
+    /// Reads a single record and retains its raw line.
+    ///
+    /// This is the same as [`Self::read_record`], but the raw line, including the original line
+    /// terminator (`\n` or `\r\n`), is also copied to `raw_line`, e.g., to write an unmodified
+    /// record back verbatim. `raw_line` is cleared before reading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut record = vcf::Record::default();
+    /// let mut raw_line = Vec::new();
+    /// reader.read_record_with_raw_line(&mut record, &mut raw_line)?;
+    /// assert_eq!(raw_line, b"sq0\t1\t.\tA\t.\t.\tPASS\t.\n");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read_record_with_raw_line(
+        &mut self,
+        record: &mut Record,
+        raw_line: &mut Vec<u8>,
+    ) -> io::Result<usize> {
+        const LINE_FEED: u8 = b'\n';
+
+        raw_line.clear();
+
+        let n = match self.max_record_length {
+            Some(max_record_length) => read_limited(&mut self.inner, max_record_length, |src| {
+                src.read_until(LINE_FEED, raw_line)
+            })?,
+            None => self.inner.read_until(LINE_FEED, raw_line)?,
+        };
+
+        if n == 0 {
+            return Ok(0);
+        }
+
+        let mut src = &raw_line[..];
+        read_record_with_field_delimiter(&mut src, record, self.field_delimiter)?;
+
+        if self.strict_samples {
+            validate_samples(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records.
@@ -406,6 +487,124 @@ where
     }
 }
 
+// Reads a record with a length limit.
+//
+// The record length includes the line terminator. If the limit is exceeded, the rest of the line
+// is discarded so that the next read starts at the following record.
+fn read_limited<R, F>(reader: &mut R, max_record_length: usize, f: F) -> io::Result<usize>
+where
+    R: BufRead,
+    F: FnOnce(&mut LimitedReader<'_, R>) -> io::Result<usize>,
+{
+    const LINE_FEED: u8 = b'\n';
+
+    let limit = u64::try_from(max_record_length)
+        .unwrap_or(u64::MAX)
+        .saturating_add(1);
+
+    let mut src = LimitedReader::new(reader, limit);
+    let result = f(&mut src);
+
+    // A truncated record may fail to read for other reasons, e.g., a split UTF-8 sequence.
+    if src.limit == 0 {
+        if src.last_byte != Some(LINE_FEED) {
+            discard_line(src.inner)?;
+        }
+
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("record length exceeds maximum ({max_record_length} bytes)"),
+        ));
+    }
+
+    result
+}
+
+// A reader that reads at most `limit` bytes and tracks the last byte consumed.
+struct LimitedReader<'r, R> {
+    inner: &'r mut R,
+    limit: u64,
+    last_byte: Option<u8>,
+}
+
+impl<'r, R> LimitedReader<'r, R> {
+    fn new(inner: &'r mut R, limit: u64) -> Self {
+        Self {
+            inner,
+            limit,
+            last_byte: None,
+        }
+    }
+}
+
+impl<R> Read for LimitedReader<'_, R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let src = self.fill_buf()?;
+        let n = src.len().min(buf.len());
+        buf[..n].copy_from_slice(&src[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl<R> BufRead for LimitedReader<'_, R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.limit == 0 {
+            return Ok(&[]);
+        }
+
+        let src = self.inner.fill_buf()?;
+        let n = usize::try_from(self.limit).map_or(src.len(), |limit| src.len().min(limit));
+        Ok(&src[..n])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        let amt = usize::try_from(self.limit).map_or(amt, |limit| amt.min(limit));
+
+        if amt > 0 {
+            // The inner buffer is unchanged until consumed, so this does not read.
+            if let Ok(src) = self.inner.fill_buf() {
+                self.last_byte = src.get(amt - 1).copied();
+            }
+        }
+
+        self.limit -= amt as u64;
+        self.inner.consume(amt);
+    }
+}
+
+fn discard_line<R>(reader: &mut R) -> io::Result<()>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            return Ok(());
+        }
+
+        match memchr::memchr(LINE_FEED, src) {
+            Some(i) => {
+                reader.consume(i + 1);
+                return Ok(());
+            }
+            None => {
+                let n = src.len();
+                reader.consume(n);
+            }
+        }
+    }
+}
+
 // Reads all bytes until a line feed ('\n') or EOF is reached.
 //
 // The buffer will not include the trailing newline ('\n' or '\r\n').
@@ -486,6 +685,132 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_strict_samples() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT
+sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT
+";
+
+        let mut record = Record::default();
+
+        let mut reader = Builder::default().build_from_reader(DATA)?;
+        reader.read_header()?;
+        assert!(reader.read_record(&mut record)? > 0);
+        assert_eq!(record.samples().as_ref(), "GT");
+
+        let mut reader = Builder::default()
+            .set_strict_samples(true)
+            .build_from_reader(DATA)?;
+        reader.read_header()?;
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_field_delimiter() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0 1 . A . . PASS . GT 0|0
+";
+
+        let mut reader = Builder::default()
+            .set_field_delimiter(b' ')
+            .build_from_reader(DATA)?;
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        assert_eq!(record.reference_sequence_name(), "sq0");
+        assert_eq!(record.reference_bases(), "A");
+        assert_eq!(record.samples().as_ref(), "GT\t0|0");
+
+        assert!(matches!(
+            Builder::default().set_field_delimiter(0x80).build_from_reader(DATA),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_max_record_length() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0
+sq0\t2\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13
+sq0\t3\t.\tA\t.\t.\tPASS\t.\tGT\t0|1
+";
+
+        let mut reader = Builder::default()
+            .set_max_record_length(28)
+            .build_from_reader(DATA)?;
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, 28);
+        assert_eq!(record.samples().as_ref(), "GT\t0|0");
+
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // The rest of the over-long record is discarded.
+        assert_eq!(reader.read_record(&mut record)?, 28);
+        assert_eq!(
+            record.variant_start().transpose()?,
+            noodles_core::Position::new(3)
+        );
+
+        let mut reader = Builder::default()
+            .set_max_record_length(28)
+            .build_from_reader(DATA)?;
+        let header = reader.read_header()?;
+
+        let mut record = RecordBuf::default();
+        assert_eq!(reader.read_record_buf(&header, &mut record)?, 28);
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_raw_line() -> io::Result<()> {
+        let data = b"sq0\t1\t.\tA\t.\t.\tPASS\t.\r\nsq0\t8\t.\tC\t.\t.\tPASS\t.";
+        let mut reader = Reader::new(&data[..]);
+
+        let mut record = Record::default();
+        let mut raw_line = Vec::new();
+
+        let n = reader.read_record_with_raw_line(&mut record, &mut raw_line)?;
+        assert_eq!(n, 22);
+        assert_eq!(raw_line, b"sq0\t1\t.\tA\t.\t.\tPASS\t.\r\n");
+        assert_eq!(record.reference_bases(), "A");
+
+        let n = reader.read_record_with_raw_line(&mut record, &mut raw_line)?;
+        assert_eq!(n, 20);
+        assert_eq!(raw_line, b"sq0\t8\t.\tC\t.\t.\tPASS\t.");
+        assert_eq!(record.reference_bases(), "C");
+
+        let n = reader.read_record_with_raw_line(&mut record, &mut raw_line)?;
+        assert_eq!(n, 0);
+        assert!(raw_line.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
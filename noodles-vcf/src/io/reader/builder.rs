@@ -13,6 +13,9 @@ use crate::io::CompressionMethod;
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    strict_samples: bool,
+    field_delimiter: Option<u8>,
+    max_record_length: Option<usize>,
 }
 
 impl Builder {
@@ -29,6 +32,61 @@ impl Builder {
         self
     }
 
+    /// Sets whether to require sample columns when a FORMAT column is present.
+    ///
+    /// By default, this is disabled, and a record with a FORMAT column but no sample columns is
+    /// read as is. When enabled, reading such a record using [`Reader::read_record`] returns an
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_strict_samples(true);
+    /// ```
+    pub fn set_strict_samples(mut self, strict_samples: bool) -> Self {
+        self.strict_samples = strict_samples;
+        self
+    }
+
+    /// Sets the field delimiter used when reading records.
+    ///
+    /// The VCF specification requires fields to be tab-delimited (`\t`), which is the default.
+    /// Setting a different delimiter is nonstandard and only intended to recover data from
+    /// malformed files, e.g., space-delimited exports. The delimiter must be an ASCII byte, and
+    /// each occurrence separates a field, i.e., consecutive delimiters are not collapsed.
+    ///
+    /// This applies to [`Reader::read_record`] and the FORMAT and sample columns are normalized
+    /// to be tab-delimited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_field_delimiter(b' ');
+    /// ```
+    pub fn set_field_delimiter(mut self, field_delimiter: u8) -> Self {
+        self.field_delimiter = Some(field_delimiter);
+        self
+    }
+
+    /// Sets the maximum record length.
+    ///
+    /// The length is in bytes and includes the line terminator. By default, record lengths are
+    /// unbounded. When set, reading a record (e.g., using [`Reader::read_record`]) that is longer
+    /// than the maximum returns an error, rather than buffering the entire record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_max_record_length(1 << 20);
+    /// ```
+    pub fn set_max_record_length(mut self, max_record_length: usize) -> Self {
+        self.max_record_length = Some(max_record_length);
+        self
+    }
+
     /// Builds a VCF reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -72,11 +130,28 @@ impl Builder {
     where
         R: Read + 'r,
     {
+        if let Some(field_delimiter) = self.field_delimiter {
+            if !field_delimiter.is_ascii() || field_delimiter == b'\n' {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid field delimiter",
+                ));
+            }
+        }
+
         let inner: Box<dyn BufRead> = match self.compression_method {
             Some(CompressionMethod::Bgzf) => Box::new(bgzf::Reader::new(reader)),
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+        reader.strict_samples = self.strict_samples;
+        reader.max_record_length = self.max_record_length;
+
+        if let Some(field_delimiter) = self.field_delimiter {
+            reader.field_delimiter = field_delimiter;
+        }
+
+        Ok(reader)
     }
 }
@@ -6,7 +6,23 @@ use std::{
 use super::read_line;
 use crate::Record;
 
+pub(crate) const DEFAULT_FIELD_DELIMITER: u8 = b'\t';
+
+const NUL: u8 = 0x00;
+
+#[cfg(any(test, feature = "async"))]
 pub(crate) fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    read_record_with_field_delimiter(reader, record, DEFAULT_FIELD_DELIMITER)
+}
+
+pub(crate) fn read_record_with_field_delimiter<R>(
+    reader: &mut R,
+    record: &mut Record,
+    delimiter: u8,
+) -> io::Result<usize>
 where
     R: BufRead,
 {
@@ -19,65 +35,121 @@ where
 
     let mut len = 0;
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "CHROM", "POS")?;
     bounds.reference_sequence_name_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "POS", "ID")?;
     bounds.variant_start_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "ID", "REF")?;
     bounds.ids_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "REF", "ALT")?;
     bounds.reference_bases_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "ALT", "QUAL")?;
     bounds.alternate_bases_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "QUAL", "FILTER")?;
     bounds.quality_score_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, delimiter, "FILTER", "INFO")?;
     bounds.filters_end = buf.len();
 
-    let (n, is_eol) = read_last_required_field(reader, buf)?;
+    let (n, is_eol) = read_last_required_field(reader, buf, delimiter)?;
     len += n;
     bounds.info_end = buf.len();
 
     if !is_eol {
         len += read_line(reader, buf)?;
+
+        if let Some(i) = buf[bounds.info_end..].find(char::from(NUL)) {
+            return Err(unexpected_nul_error("samples", i));
+        }
+
+        // The FORMAT and sample columns are stored tab-delimited.
+        if delimiter != DEFAULT_FIELD_DELIMITER {
+            let samples = buf.split_off(bounds.info_end);
+            let delimiter = char::from(delimiter);
+            let separator = char::from(DEFAULT_FIELD_DELIMITER);
+            buf.extend(
+                samples
+                    .chars()
+                    .map(|c| if c == delimiter { separator } else { c }),
+            );
+        }
     }
 
     Ok(len)
 }
 
-fn read_required_field<R>(reader: &mut R, dst: &mut String) -> io::Result<usize>
+fn unexpected_nul_error(name: &str, offset: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected NUL byte in {name} at offset {offset}"),
+    )
+}
+
+pub(super) fn validate_samples(record: &Record) -> io::Result<()> {
+    const DELIMITER: char = '\t';
+
+    let samples = record.samples();
+    let src = samples.as_ref();
+
+    if !src.is_empty() && !src.contains(DELIMITER) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing sample columns: FORMAT column is present but no samples follow",
+        ));
+    }
+
+    Ok(())
+}
+
+fn read_required_field<R>(
+    reader: &mut R,
+    dst: &mut String,
+    delimiter: u8,
+    name: &str,
+    next_name: &str,
+) -> io::Result<usize>
 where
     R: BufRead,
 {
-    let (len, is_eol) = read_field(reader, dst)?;
+    let (len, is_eol) = read_field(reader, dst, delimiter, name)?;
 
     if is_eol {
-        Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected EOL"))
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unexpected end of line: missing {next_name}"),
+        ))
     } else {
         Ok(len)
     }
 }
 
-fn read_last_required_field<R>(reader: &mut R, dst: &mut String) -> io::Result<(usize, bool)>
+fn read_last_required_field<R>(
+    reader: &mut R,
+    dst: &mut String,
+    delimiter: u8,
+) -> io::Result<(usize, bool)>
 where
     R: BufRead,
 {
-    read_field(reader, dst)
+    read_field(reader, dst, delimiter, "INFO")
 }
 
-fn read_field<R>(reader: &mut R, dst: &mut String) -> io::Result<(usize, bool)>
+fn read_field<R>(
+    reader: &mut R,
+    dst: &mut String,
+    delimiter: u8,
+    name: &str,
+) -> io::Result<(usize, bool)>
 where
     R: BufRead,
 {
-    use memchr::memchr2;
+    use memchr::{memchr, memchr2};
 
-    const DELIMITER: u8 = b'\t';
     const LINE_FEED: u8 = b'\n';
     const CARRIAGE_RETURN: u8 = b'\r';
 
@@ -91,7 +163,7 @@ where
             break;
         }
 
-        let (mut buf, n) = match memchr2(DELIMITER, LINE_FEED, src) {
+        let (mut buf, n) = match memchr2(delimiter, LINE_FEED, src) {
             Some(i) => {
                 r#match = Some(src[i]);
                 (&src[..i], i + 1)
@@ -103,6 +175,10 @@ where
             buf = head;
         }
 
+        if let Some(i) = memchr(NUL, buf) {
+            return Err(unexpected_nul_error(name, len + i));
+        }
+
         let s = str::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         dst.push_str(s);
 
@@ -143,4 +219,190 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_record_with_field_delimiter() -> io::Result<()> {
+        let mut src = &b"sq0 1 . A . . . . GT 0|0\n"[..];
+        let mut record = Record::default();
+        read_record_with_field_delimiter(&mut src, &mut record, b' ')?;
+        assert_eq!(record.fields().buf, "sq01.A....GT\t0|0");
+        assert_eq!(record.fields().bounds, Bounds::default());
+        assert_eq!(record.samples().as_ref(), "GT\t0|0");
+
+        let mut src = &b"sq0 1 . A . . . .\n"[..];
+        assert!(matches!(
+            read_record(&mut src, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_nul_byte() {
+        fn t(mut src: &[u8], expected: &str) {
+            let mut record = Record::default();
+
+            match read_record(&mut src, &mut record) {
+                Err(e) => {
+                    assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                    assert_eq!(e.to_string(), expected);
+                }
+                Ok(_) => panic!("expected error"),
+            }
+        }
+
+        t(
+            b"sq0\t1\t.\tA\x00C\t.\t.\t.\t.\n",
+            "unexpected NUL byte in REF at offset 1",
+        );
+        t(
+            b"sq0\t1\t.\tA\t.\t.\t.\tDP=\x00\n",
+            "unexpected NUL byte in INFO at offset 3",
+        );
+        t(
+            b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0\x00\n",
+            "unexpected NUL byte in samples at offset 4",
+        );
+    }
+
+    #[test]
+    fn test_read_record_with_missing_fields() {
+        fn t(mut src: &[u8], expected: &str) {
+            let mut record = Record::default();
+
+            match read_record(&mut src, &mut record) {
+                Err(e) => {
+                    assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+                    assert_eq!(e.to_string(), expected);
+                }
+                Ok(_) => panic!("expected error"),
+            }
+        }
+
+        t(b"sq0\n", "unexpected end of line: missing POS");
+        t(b"sq0\t1\n", "unexpected end of line: missing ID");
+        t(b"sq0\t1\t.\tA\n", "unexpected end of line: missing ALT");
+        t(
+            b"sq0\t1\t.\tA\t.\t.\t.\n",
+            "unexpected end of line: missing INFO",
+        );
+    }
+
+    #[test]
+    fn test_validate_samples() -> io::Result<()> {
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(validate_samples(&record).is_ok());
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0|0\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(validate_samples(&record).is_ok());
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(matches!(
+            validate_samples(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_spanning_deletion() -> io::Result<()> {
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t1\t.\tA\tT,*\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(record.has_spanning_deletion());
+
+        let mut src = &b"sq0\t1\t.\tA\tT\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(!record.has_spanning_deletion());
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(!record.has_spanning_deletion());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_ploidy() -> io::Result<()> {
+        let header = crate::Header::default();
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT:GQ\t0|0:13\t0/1:8\t.:5\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert_eq!(record.ploidy(&header)?, Some(2));
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0|0\t1\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert_eq!(record.ploidy(&header)?, None);
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGQ\t13\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert_eq!(record.ploidy(&header)?, None);
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert_eq!(record.ploidy(&header)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_quality_ordering_key() -> io::Result<()> {
+        use crate::record::QualKey;
+
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        let missing = record.quality_ordering_key()?;
+        assert_eq!(missing, QualKey::Missing);
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t0.0\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        let zero = record.quality_ordering_key()?;
+        assert_eq!(zero, QualKey::Value(0.0));
+
+        assert!(missing < zero);
+
+        let mut src = &b"sq0\t1\t.\tA\t.\tnd\t.\t.\n"[..];
+        read_record(&mut src, &mut record)?;
+        assert!(matches!(
+            record.quality_ordering_key(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_added_info_field() -> io::Result<()> {
+        use crate::variant::record::Info as _;
+
+        let header = crate::Header::default();
+        let mut record = Record::default();
+
+        let mut src = &b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0|0\n"[..];
+        read_record(&mut src, &mut record)?;
+        record.add_info_field("DP", Some("30"));
+        assert_eq!(record.fields().buf, "sq01.A...DP=30GT\t0|0");
+        assert_eq!(record.fields().bounds.info_range(), 9..14);
+        assert_eq!(record.info().len(), 1);
+        assert!(record.info().get(&header, "DP").is_some());
+        assert_eq!(record.samples().as_ref(), "GT\t0|0");
+
+        record.add_info_field("SOMATIC", None);
+        assert_eq!(record.fields().buf, "sq01.A...DP=30;SOMATICGT\t0|0");
+        assert_eq!(record.info().len(), 2);
+        assert_eq!(record.samples().as_ref(), "GT\t0|0");
+
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use std::{error, fmt};
 
-use crate::variant::record_buf::Filters;
+use crate::variant::record_buf::{filters::is_valid_filter, Filters};
 
 /// An error when raw VCF record filters fail to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -50,13 +50,6 @@ pub(super) fn parse_filters(s: &str, filters: &mut Filters) -> Result<(), ParseE
     Ok(())
 }
 
-fn is_valid_filter(s: &str) -> bool {
-    match s {
-        "" | "0" => false,
-        _ => s.chars().all(|c| !c.is_whitespace()),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,4 +100,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_filters_with_reserved_characters() -> Result<(), Box<dyn std::error::Error>> {
+        let mut filters = Filters::default();
+
+        // Semicolons only delimit filters, so parsed filters never contain them.
+        parse_filters("q10;s50", &mut filters)?;
+        assert!(filters
+            .as_ref()
+            .iter()
+            .all(|filter| is_valid_filter(filter)));
+        assert_eq!(Filters::from_fail_set(filters.as_ref().clone())?, filters);
+
+        assert!(!is_valid_filter("q10;s50"));
+
+        assert_eq!(
+            parse_filters("q\u{a0}10", &mut filters),
+            Err(ParseError::InvalidFilter)
+        );
+
+        Ok(())
+    }
 }
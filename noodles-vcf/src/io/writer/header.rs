@@ -116,6 +116,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_info_other_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let src = "##fileformat=VCFv4.4
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\",Foo=\"Bar\",Source=\"noodles\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let header: Header = src.parse()?;
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header)?;
+
+        assert_eq!(buf, src.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_unquoted_info_other_fields() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let src = "##fileformat=VCFv4.4
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\",Foo=Bar>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let header: Header = src.parse()?;
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header)?;
+
+        // Other field values are always written quoted.
+        let expected = b"##fileformat=VCFv4.4
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\",Foo=\"Bar\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        assert_eq!(buf, expected);
+
+        let actual: Header = std::str::from_utf8(&buf)?.parse()?;
+        assert_eq!(actual, header);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_column_names() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -104,6 +104,8 @@ where
     Ok(())
 }
 
+// Other field values are always written as quoted strings, regardless of whether they were quoted
+// in the source, e.g., `Foo=Bar` is written as `Foo="Bar"`.
 fn write_other_fields<W, S>(writer: &mut W, other_fields: &OtherFields<S>) -> io::Result<()>
 where
     W: Write,
@@ -5,6 +5,7 @@ pub(crate) mod fields;
 mod filters;
 mod ids;
 mod info;
+mod qual_key;
 pub mod samples;
 
 use std::{fmt, io};
@@ -13,7 +14,8 @@ use noodles_core::Position;
 
 use self::fields::Fields;
 pub use self::{
-    alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info, samples::Samples,
+    alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info, qual_key::QualKey,
+    samples::Samples,
 };
 use super::Header;
 
@@ -56,11 +58,34 @@ impl Record {
         self.0.alternate_bases()
     }
 
+    /// Returns whether the alternate bases include a spanning deletion (`*`).
+    ///
+    /// The `*` allele denotes that the position is missing due to an upstream deletion. It is not
+    /// a base sequence, so callers that interpret alternate alleles as bases, e.g., to compute
+    /// allele lengths, should skip it.
+    pub fn has_spanning_deletion(&self) -> bool {
+        const SPANNING_DELETION: &str = "*";
+        const DELIMITER: char = ',';
+
+        self.alternate_bases()
+            .as_ref()
+            .split(DELIMITER)
+            .any(|allele| allele == SPANNING_DELETION)
+    }
+
     /// Returns the quality score.
     pub fn quality_score(&self) -> Option<io::Result<f32>> {
         self.0.quality_score()
     }
 
+    /// Returns the quality score as an ordering key.
+    ///
+    /// A missing quality score (`.`) orders below all numeric values. See
+    /// [`QualKey::cmp_missing_last`] to order missing values last.
+    pub fn quality_ordering_key(&self) -> io::Result<QualKey> {
+        self.quality_score().transpose().map(QualKey::from)
+    }
+
     /// Returns the filters.
     pub fn filters(&self) -> Filters<'_> {
         self.0.filters()
@@ -71,10 +96,63 @@ impl Record {
         self.0.info()
     }
 
+    /// Adds a field to the info.
+    ///
+    /// The field is appended to the end of the info as `KEY=VALUE` or, if `value` is `None`, as
+    /// a flag `KEY`. A missing info (`.`) is replaced by the field.
+    ///
+    /// The key and value are not validated.
+    pub fn add_info_field(&mut self, key: &str, value: Option<&str>) {
+        self.0.add_info_field(key, value);
+    }
+
     /// Returns the samples.
     pub fn samples(&self) -> Samples<'_> {
         self.0.samples()
     }
+
+    /// Returns the ploidy common to all samples.
+    ///
+    /// The ploidy of a sample is the number of alleles in its genotype (`GT`). Samples with a
+    /// missing genotype are ignored.
+    ///
+    /// This returns `None` if there is no genotype field, if all genotypes are missing, or if the
+    /// samples do not have the same ploidy.
+    pub fn ploidy(&self, header: &Header) -> io::Result<Option<usize>> {
+        use crate::variant::record::samples::{keys::key, series::Value, Series as _};
+
+        let samples = self.samples();
+
+        let series = match samples.select(key::GENOTYPE) {
+            Some(series) => series,
+            None => return Ok(None),
+        };
+
+        let mut ploidy = None;
+
+        for result in series.iter(header) {
+            let n = match result? {
+                Some(Value::Genotype(genotype)) => genotype
+                    .iter()
+                    .try_fold(0, |n, result| result.map(|_| n + 1))?,
+                Some(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid genotype value",
+                    ))
+                }
+                None => continue,
+            };
+
+            match ploidy {
+                None => ploidy = Some(n),
+                Some(m) if m != n => return Ok(None),
+                Some(_) => {}
+            }
+        }
+
+        Ok(ploidy)
+    }
 }
 
 impl fmt::Debug for Record {
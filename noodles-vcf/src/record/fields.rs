@@ -86,6 +86,32 @@ impl Fields {
         Info::new(src)
     }
 
+    pub(super) fn add_info_field(&mut self, key: &str, value: Option<&str>) {
+        const DELIMITER: char = ';';
+        const SEPARATOR: char = '=';
+
+        let range = self.bounds.info_range();
+
+        let mut field = String::new();
+
+        if &self.buf[range.clone()] == MISSING {
+            self.buf.replace_range(range.clone(), "");
+            self.bounds.info_end = range.start;
+        } else {
+            field.push(DELIMITER);
+        }
+
+        field.push_str(key);
+
+        if let Some(value) = value {
+            field.push(SEPARATOR);
+            field.push_str(value);
+        }
+
+        self.buf.insert_str(self.bounds.info_end, &field);
+        self.bounds.info_end += field.len();
+    }
+
     pub(super) fn samples(&self) -> Samples<'_> {
         const DELIMITER: char = '\t';
 
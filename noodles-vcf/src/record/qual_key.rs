@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+
+/// A VCF record quality score (`QUAL`) ordering key.
+///
+/// A missing quality score (`.`) orders below all numeric values. Numeric values are ordered by
+/// their IEEE 754 total ordering.
+#[derive(Clone, Copy, Debug)]
+pub enum QualKey {
+    /// The quality score is missing (`.`).
+    Missing,
+    /// A quality score.
+    Value(f32),
+}
+
+impl QualKey {
+    /// Returns the quality score, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::QualKey;
+    /// assert!(QualKey::Missing.value().is_none());
+    /// assert_eq!(QualKey::Value(13.0).value(), Some(13.0));
+    /// ```
+    pub fn value(&self) -> Option<f32> {
+        match self {
+            Self::Missing => None,
+            Self::Value(n) => Some(*n),
+        }
+    }
+
+    /// Compares two keys, ordering a missing quality score above all numeric values.
+    ///
+    /// This is the same as [`Ord::cmp`] except missing values sort last instead of first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use noodles_vcf::record::QualKey;
+    ///
+    /// assert_eq!(
+    ///     QualKey::Missing.cmp_missing_last(&QualKey::Value(0.0)),
+    ///     Ordering::Greater
+    /// );
+    /// ```
+    pub fn cmp_missing_last(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Missing, Self::Missing) => Ordering::Equal,
+            (Self::Missing, Self::Value(_)) => Ordering::Greater,
+            (Self::Value(_), Self::Missing) => Ordering::Less,
+            (Self::Value(a), Self::Value(b)) => a.total_cmp(b),
+        }
+    }
+}
+
+impl From<Option<f32>> for QualKey {
+    fn from(quality_score: Option<f32>) -> Self {
+        match quality_score {
+            Some(n) => Self::Value(n),
+            None => Self::Missing,
+        }
+    }
+}
+
+impl PartialEq for QualKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QualKey {}
+
+impl PartialOrd for QualKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QualKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Missing, Self::Missing) => Ordering::Equal,
+            (Self::Missing, Self::Value(_)) => Ordering::Less,
+            (Self::Value(_), Self::Missing) => Ordering::Greater,
+            (Self::Value(a), Self::Value(b)) => a.total_cmp(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmp() {
+        assert!(QualKey::Missing < QualKey::Value(0.0));
+        assert!(QualKey::Missing < QualKey::Value(-1.0));
+        assert!(QualKey::Value(0.0) < QualKey::Value(13.0));
+        assert_eq!(QualKey::Missing, QualKey::Missing);
+        assert_eq!(QualKey::Value(5.0), QualKey::Value(5.0));
+    }
+
+    #[test]
+    fn test_cmp_missing_last() {
+        assert_eq!(
+            QualKey::Missing.cmp_missing_last(&QualKey::Value(0.0)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            QualKey::Value(0.0).cmp_missing_last(&QualKey::Value(13.0)),
+            Ordering::Less
+        );
+        assert_eq!(
+            QualKey::Missing.cmp_missing_last(&QualKey::Missing),
+            Ordering::Equal
+        );
+    }
+}
@@ -3,7 +3,7 @@
 mod alternate_bases;
 pub mod builder;
 mod convert;
-mod filters;
+pub mod filters;
 pub mod ids;
 pub mod info;
 pub mod samples;
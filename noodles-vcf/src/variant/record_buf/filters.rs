@@ -1,10 +1,14 @@
-use std::io;
+//! Variant record filters buffer.
+
+use std::{error, fmt, io};
 
 use indexmap::IndexSet;
 
 use crate::Header;
 
 const PASS: &str = "PASS";
+const MISSING: &str = ".";
+const DELIMITER: char = ';';
 
 /// A variant record filters buffer.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -24,6 +28,47 @@ impl Filters {
         [String::from(PASS)].into_iter().collect()
     }
 
+    /// Creates filters from a set of failed filters.
+    ///
+    /// The set is used as is, i.e., it is not recollected. A set with only `PASS` is a PASS
+    /// filter.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the set is empty, a filter is invalid, or `PASS` is combined with
+    /// other filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indexmap::IndexSet;
+    /// use noodles_vcf::variant::record_buf::Filters;
+    ///
+    /// let set: IndexSet<_> = [String::from("q10")].into_iter().collect();
+    /// let filters = Filters::from_fail_set(set.clone())?;
+    /// assert_eq!(filters.as_ref(), &set);
+    /// # Ok::<_, noodles_vcf::variant::record_buf::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn from_fail_set(set: IndexSet<String>) -> Result<Self, TryFromIteratorError> {
+        if set.is_empty() {
+            return Err(TryFromIteratorError::Empty);
+        }
+
+        if set.len() == 1 && set.contains(PASS) {
+            return Ok(Self::pass());
+        }
+
+        for filter in &set {
+            if filter == PASS {
+                return Err(TryFromIteratorError::UnexpectedPass);
+            } else if !is_valid_filter(filter) {
+                return Err(TryFromIteratorError::InvalidFilter(filter.clone()));
+            }
+        }
+
+        Ok(Self(set))
+    }
+
     /// Returns whether this is a PASS filter.
     ///
     /// # Examples
@@ -45,6 +90,72 @@ impl Filters {
     }
 }
 
+/// An error returned when filters fail to be created from a set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromIteratorError {
+    /// The set is empty.
+    Empty,
+    /// A filter is invalid.
+    InvalidFilter(String),
+    /// `PASS` is combined with other filters.
+    UnexpectedPass,
+}
+
+impl error::Error for TryFromIteratorError {}
+
+impl fmt::Display for TryFromIteratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::InvalidFilter(filter) => write!(f, "invalid filter: {filter}"),
+            Self::UnexpectedPass => write!(f, "unexpected {PASS}"),
+        }
+    }
+}
+
+// § 1.6.1.7 "Fixed fields: FILTER" (2023-08-23): "...no whitespace or semicolons permitted..."
+//
+// Semicolons are reserved as the filter delimiter, and `0` is reserved by the specification.
+// The empty string cannot be written unambiguously.
+pub(crate) fn is_valid_filter(s: &str) -> bool {
+    match s {
+        "" | "0" => false,
+        _ => s.chars().all(|c| !c.is_whitespace() && c != ';'),
+    }
+}
+
+impl fmt::Display for Filters {
+    /// Formats the filters as the raw VCF FILTER field.
+    ///
+    /// Empty filters are formatted as missing (`.`), and multiple filters are delimited by `;`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::Filters;
+    ///
+    /// assert_eq!(Filters::pass().to_string(), "PASS");
+    ///
+    /// let filters: Filters = [String::from("q10"), String::from("s50")].into_iter().collect();
+    /// assert_eq!(filters.to_string(), "q10;s50");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str(MISSING);
+        }
+
+        for (i, filter) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{DELIMITER}")?;
+            }
+
+            f.write_str(filter)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl AsRef<IndexSet<String>> for Filters {
     fn as_ref(&self) -> &IndexSet<String> {
         &self.0
@@ -104,3 +215,67 @@ impl crate::variant::record::Filters for &Filters {
         Box::new(self.0.iter().map(|filter| Ok(filter.as_ref())))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Filters::default().to_string(), ".");
+        assert_eq!(Filters::pass().to_string(), "PASS");
+
+        let filters: Filters = [String::from("q10"), String::from("s50")]
+            .into_iter()
+            .collect();
+        assert_eq!(filters.to_string(), "q10;s50");
+    }
+
+    #[test]
+    fn test_is_valid_filter() {
+        assert!(is_valid_filter("q10"));
+        assert!(is_valid_filter("s50"));
+        assert!(is_valid_filter("LowQual"));
+
+        assert!(!is_valid_filter(""));
+        assert!(!is_valid_filter("0"));
+        assert!(!is_valid_filter("q10;s50"));
+        assert!(!is_valid_filter("q 10"));
+        assert!(!is_valid_filter("q\u{a0}10"));
+    }
+
+    #[test]
+    fn test_from_fail_set() {
+        fn build_set(filters: &[&str]) -> IndexSet<String> {
+            filters.iter().map(|s| String::from(*s)).collect()
+        }
+
+        let set = build_set(&["q10", "s50"]);
+        assert_eq!(Filters::from_fail_set(set.clone()), Ok(Filters(set)));
+
+        assert_eq!(
+            Filters::from_fail_set(build_set(&["PASS"])),
+            Ok(Filters::pass())
+        );
+
+        assert_eq!(
+            Filters::from_fail_set(IndexSet::new()),
+            Err(TryFromIteratorError::Empty)
+        );
+
+        assert_eq!(
+            Filters::from_fail_set(build_set(&["q10", "q 10"])),
+            Err(TryFromIteratorError::InvalidFilter(String::from("q 10")))
+        );
+
+        assert_eq!(
+            Filters::from_fail_set(build_set(&["q10;s50"])),
+            Err(TryFromIteratorError::InvalidFilter(String::from("q10;s50")))
+        );
+
+        assert_eq!(
+            Filters::from_fail_set(build_set(&["q10", "PASS"])),
+            Err(TryFromIteratorError::UnexpectedPass)
+        );
+    }
+}